@@ -128,7 +128,7 @@ async function processGameStatus(
       break;
 
     case GameStatus.Waiting:
-      await handleWaitingPhase(ctx, solanaClient, gameRound, gameState, gameConfig, now);
+      await handleWaitingPhase(ctx, solanaClient, gameRound, gameState, now);
       break;
 
     // Large game phases removed for small games MVP:
@@ -152,12 +152,11 @@ async function handleWaitingPhase(
   solanaClient: SolanaClient,
   gameRound: any,
   gameState: any,
-  gameConfig: any,
   now: number
 ) {
-  // Calculate when waiting phase should end
-  const waitingDuration = gameConfig.smallGameDurationConfig.waitingPhaseDuration;
-  const waitingEndTime = gameRound.startTimestamp * 1000 + waitingDuration * 1000;
+  // Betting closes at the round's on-chain close time, which late bets and
+  // waiting window extensions push back
+  const waitingEndTime = gameRound.closeAt * 1000;
 
   if (now >= waitingEndTime) {
     console.log(
@@ -270,6 +269,16 @@ async function handleWinnerRandomness(
           status: "idle",
           resolvingPhaseEnd: now,
         });
+
+        // Reclaim rent from round results past their retention period
+        try {
+          const closed = await solanaClient.closeExpiredRoundResults();
+          if (closed.length > 0) {
+            console.log(`Closed ${closed.length} expired round results`);
+          }
+        } catch (error) {
+          console.error("Failed to close expired round results:", error);
+        }
       } else {
         throw new Error("Transaction confirmation failed");
       }
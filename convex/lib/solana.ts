@@ -2,7 +2,15 @@
 "use node";
 import * as anchor from "@coral-xyz/anchor";
 import { Connection, PublicKey, Keypair, Transaction, VersionedTransaction } from "@solana/web3.js";
-import { GameConfig, GameRound, GameStatus, DOMIN8_PROGRAM_ID, PDA_SEEDS } from "./types";
+import {
+  GameConfig,
+  GameRound,
+  GameStatus,
+  DEFAULT_ROOM_ID,
+  ROUND_RESULT_RETENTION_SECONDS,
+  DOMIN8_PROGRAM_ID,
+  PDA_SEEDS,
+} from "./types";
 import { Buffer } from "buffer";
 import bs58 from "bs58";
 
@@ -88,32 +96,81 @@ export class SolanaClient {
     this.program = new anchor.Program<Domin8Prgm>(IDL as Domin8Prgm, this.provider);
   }
 
-  // Get PDAs for the game accounts
+  // Get PDAs for the game accounts (room-scoped accounts use the default room)
   private getPDAs() {
     const [gameConfig] = PublicKey.findProgramAddressSync(
       [PDA_SEEDS.GAME_CONFIG],
       DOMIN8_PROGRAM_ID
     );
 
-    const [gameRound] = PublicKey.findProgramAddressSync([PDA_SEEDS.GAME_ROUND], DOMIN8_PROGRAM_ID);
+    const roomId = new anchor.BN(DEFAULT_ROOM_ID).toArrayLike(Buffer, "le", 8);
 
-    const [vault] = PublicKey.findProgramAddressSync([PDA_SEEDS.VAULT], DOMIN8_PROGRAM_ID);
+    const [room] = PublicKey.findProgramAddressSync([PDA_SEEDS.ROOM, roomId], DOMIN8_PROGRAM_ID);
 
-    return { gameConfig, gameRound, vault };
+    const [gameRound] = PublicKey.findProgramAddressSync(
+      [PDA_SEEDS.GAME_ROUND, roomId],
+      DOMIN8_PROGRAM_ID
+    );
+
+    const [vault] = PublicKey.findProgramAddressSync([PDA_SEEDS.VAULT, roomId], DOMIN8_PROGRAM_ID);
+
+    const [houseVault] = PublicKey.findProgramAddressSync(
+      [PDA_SEEDS.HOUSE_VAULT],
+      DOMIN8_PROGRAM_ID
+    );
+
+    const [jackpot] = PublicKey.findProgramAddressSync([PDA_SEEDS.JACKPOT], DOMIN8_PROGRAM_ID);
+
+    const [rakebackReserve] = PublicKey.findProgramAddressSync(
+      [PDA_SEEDS.RAKEBACK_RESERVE],
+      DOMIN8_PROGRAM_ID
+    );
+
+    return { gameConfig, room, gameRound, vault, houseVault, jackpot, rakebackReserve };
   }
 
-  // Get current game configuration
+  // Per-wallet PDA for one of the player account seeds
+  private getPlayerPDA(seed: Buffer, wallet: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync([seed, wallet.toBuffer()], DOMIN8_PROGRAM_ID)[0];
+  }
+
+  // Addresses whose accounts have been created, in the same order
+  private async existingAccounts(addresses: PublicKey[]): Promise<PublicKey[]> {
+    const accounts = await this.connection.getMultipleAccountsInfo(addresses);
+    return addresses.filter((_, i) => accounts[i] !== null);
+  }
+
+  // Optional program accounts are passed as null until they have been initialized
+  private async ifInitialized(address: PublicKey): Promise<PublicKey | null> {
+    const account = await this.connection.getAccountInfo(address);
+    return account ? address : null;
+  }
+
+  // Round result PDA of the default room, created when the round is resolved
+  private getRoundResultPDA(roundId: anchor.BN): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [
+        PDA_SEEDS.ROUND_RESULT,
+        new anchor.BN(DEFAULT_ROOM_ID).toArrayLike(Buffer, "le", 8),
+        roundId.toArrayLike(Buffer, "le", 8),
+      ],
+      DOMIN8_PROGRAM_ID
+    )[0];
+  }
+
+  // Get current game configuration (bet limits, fee and duration come from the default room)
   async getGameConfig(): Promise<GameConfig> {
-    const { gameConfig } = this.getPDAs();
+    const { gameConfig, room } = this.getPDAs();
     const account = await this.program.account.gameConfig.fetch(gameConfig);
+    const roomAccount = await this.program.account.room.fetch(room);
 
     return {
       authority: account.authority.toBase58(), // Convert PublicKey to string
       treasury: account.treasury.toBase58(), // Convert PublicKey to string
-      houseFeeBasisPoints: account.houseFeeBasisPoints,
-      minBetLamports: account.minBetLamports.toNumber(),
+      houseFeeBasisPoints: roomAccount.houseFeeBasisPoints,
+      minBetLamports: roomAccount.minBet.toNumber(),
       smallGameDurationConfig: {
-        waitingPhaseDuration: account.smallGameDurationConfig.waitingPhaseDuration.toNumber(),
+        waitingPhaseDuration: roomAccount.durationConfig.waitingPhaseDuration.toNumber(),
       },
       // ORAO VRF configuration
       vrfFeeLamports: account.vrfFeeLamports.toNumber(),
@@ -139,6 +196,7 @@ export class SolanaClient {
       roundId: account.roundId.toNumber(),
       status,
       startTimestamp: account.startTimestamp.toNumber(),
+      closeAt: account.closeAt.toNumber(),
       players: account.players.map((p) => ({
         wallet: p.wallet.toBase58(), // Convert PublicKey to string
         totalBet: p.totalBet.toNumber(),
@@ -160,14 +218,40 @@ export class SolanaClient {
 
   // UNIFIED INSTRUCTION: Progress game from Waiting directly to AwaitingWinnerRandomness with ORAO VRF
   async unifiedProgressToResolution(): Promise<string> {
-    const { gameConfig, gameRound } = this.getPDAs();
+    const { gameConfig, room, gameRound, vault, houseVault } = this.getPDAs();
 
     // Build VRF request PDA
     const vrfRequestPda = this.getVrfRequestPDA();
 
+    // An under-filled round is refunded: every player's wallet, the Game Coins
+    // balance of anyone who bet from it, and their stats accounts
+    const gameRoundAccount = await this.program.account.gameRound.fetch(gameRound);
+    const wallets: PublicKey[] = gameRoundAccount.players.map((player: any) => player.wallet);
+    const remainingAccounts = wallets.map((wallet) => ({
+      pubkey: wallet,
+      isWritable: true,
+      isSigner: false,
+    }));
+    for (const player of gameRoundAccount.players) {
+      if (player.balanceBet.gtn(0)) {
+        remainingAccounts.push({
+          pubkey: this.getPlayerPDA(PDA_SEEDS.PLAYER_BALANCE, player.wallet),
+          isWritable: true,
+          isSigner: false,
+        });
+      }
+    }
+    const playerStats = await this.existingAccounts(
+      wallets.map((wallet) => this.getPlayerPDA(PDA_SEEDS.PLAYER_STATS, wallet))
+    );
+    for (const pubkey of playerStats) {
+      remainingAccounts.push({ pubkey, isWritable: true, isSigner: false });
+    }
+
     const tx = await this.program.methods
       .unifiedProgressToResolution()
       .accounts({
+        room,
         gameRound: gameRound, // IDL shows this should be game_round but Anchor converts to camelCase
         config: gameConfig,
         crank: this.authority.publicKey,
@@ -175,8 +259,16 @@ export class SolanaClient {
         networkState: await this.getOraoNetworkState(),
         treasury: await this.getOraoTreasury(),
         vrfRequest: vrfRequestPda,
+        // Matches a solo player's stake when solo mode is on
+        houseVault: await this.ifInitialized(houseVault),
+        // Refunds an under-filled round out of the room vault
+        vault,
+        betMint: null,
+        vaultTokenAccount: null,
+        tokenProgram: null,
         systemProgram: anchor.web3.SystemProgram.programId,
       } as any) // Temporary until TypeScript types are properly generated
+      .remainingAccounts(remainingAccounts)
       .rpc();
 
     return tx;
@@ -185,30 +277,78 @@ export class SolanaClient {
   // UNIFIED INSTRUCTION: Resolve winner using ORAO VRF and immediately distribute winnings
   async unifiedResolveAndDistribute(vrfRequestPubkeyStr: string): Promise<string> {
     const vrfRequestPubkey = new PublicKey(vrfRequestPubkeyStr);
-    const { gameConfig, gameRound, vault } = this.getPDAs();
+    const { gameConfig, room, gameRound, vault, houseVault, jackpot, rakebackReserve } =
+      this.getPDAs();
 
     // Fetch current game round to get player accounts
     const gameRoundAccount = await this.program.account.gameRound.fetch(gameRound);
 
-    // Get treasury from game config
+    // Relayer and current season come from the game config
     const gameConfigAccount = await this.program.account.gameConfig.fetch(gameConfig);
 
     // Prepare remaining accounts - all player accounts that could potentially win
-    const remainingAccounts = gameRoundAccount.players.map((player: any) => ({
-      pubkey: player.wallet,
+    const wallets: PublicKey[] = gameRoundAccount.players.map((player: any) => player.wallet);
+    const remainingAccounts = wallets.map((wallet) => ({
+      pubkey: wallet,
       isWritable: true, // Players' accounts will receive funds if they win
       isSigner: false, // Players are not signing this transaction
     }));
 
+    // Players' stats accounts record the round's result
+    const playerStats = await this.existingAccounts(
+      wallets.map((wallet) => this.getPlayerPDA(PDA_SEEDS.PLAYER_STATS, wallet))
+    );
+    for (const pubkey of playerStats) {
+      remainingAccounts.push({ pubkey, isWritable: true, isSigner: false });
+    }
+
+    // Referred players' referrals and their referrers' earnings accounts
+    const referrals = await this.existingAccounts(
+      wallets.map((wallet) => this.getPlayerPDA(PDA_SEEDS.REFERRAL, wallet))
+    );
+    for (const pubkey of referrals) {
+      const referral = await this.program.account.referral.fetch(pubkey);
+      remainingAccounts.push(
+        { pubkey, isWritable: false, isSigner: false },
+        {
+          pubkey: this.getPlayerPDA(PDA_SEEDS.REFERRER, referral.referrer),
+          isWritable: true,
+          isSigner: false,
+        }
+      );
+    }
+
     const tx = await this.program.methods
       .unifiedResolveAndDistribute()
       .accounts({
+        room,
         gameRound: gameRound, // IDL shows this should be game_round but Anchor converts to camelCase
         config: gameConfig,
         vault,
+        roundResult: this.getRoundResultPDA(gameRoundAccount.roundId),
         crank: this.authority.publicKey,
         vrfRequest: vrfRequestPubkey,
-        treasury: gameConfigAccount.treasury,
+        // Settles solo rounds against the house
+        houseVault: await this.ifInitialized(houseVault),
+        jackpot: await this.ifInitialized(jackpot),
+        rakebackReserve: await this.ifInitialized(rakebackReserve),
+        // Reimbursed for the round's sponsored bets once configured
+        relayer: gameConfigAccount.relayer.equals(PublicKey.default)
+          ? null
+          : gameConfigAccount.relayer,
+        // The crank only resolves native SOL pools, which need no token accounts
+        betMint: null,
+        vaultTokenAccount: null,
+        treasuryTokenAccount: null,
+        tokenProgram: null,
+        // Scores the round while the room is running a tournament
+        tournament: gameRoundAccount.tournament.equals(PublicKey.default)
+          ? null
+          : gameRoundAccount.tournament,
+        // Funds the current season's prize pool while it is active
+        season: gameConfigAccount.currentSeason.equals(PublicKey.default)
+          ? null
+          : gameConfigAccount.currentSeason,
         systemProgram: anchor.web3.SystemProgram.programId,
       } as any) // Temporary until TypeScript types are properly generated
       .remainingAccounts(remainingAccounts)
@@ -217,6 +357,30 @@ export class SolanaClient {
     return tx;
   }
 
+  // Close round results past the on-chain retention period to reclaim their rent
+  async closeExpiredRoundResults(): Promise<string[]> {
+    const { gameConfig } = this.getPDAs();
+    const cutoff = Math.floor(Date.now() / 1000) - ROUND_RESULT_RETENTION_SECONDS;
+    const results = await this.program.account.roundResult.all();
+
+    const signatures: string[] = [];
+    for (const result of results) {
+      if (result.account.resolvedAt.toNumber() > cutoff) continue;
+
+      const tx = await this.program.methods
+        .closeRoundResult()
+        .accounts({
+          config: gameConfig,
+          roundResult: result.publicKey,
+          authority: this.authority.publicKey,
+        } as any) // Temporary until TypeScript types are properly generated
+        .rpc();
+      signatures.push(tx);
+    }
+
+    return signatures;
+  }
+
   // Check if ORAO VRF is fulfilled
   async checkVrfFulfillment(vrfRequestPubkeyStr: string | null): Promise<boolean> {
     if (!vrfRequestPubkeyStr) {
@@ -48,6 +48,7 @@ export interface GameRound {
   roundId: number;
   status: GameStatus;
   startTimestamp: number;
+  closeAt: number; // Unix timestamp when betting closes, including extensions
   players: PlayerEntry[];
   initialPot: number;
   winner: string | null; // PublicKey as base58 string, or null if no winner yet
@@ -57,11 +58,26 @@ export interface GameRound {
   randomnessFulfilled: boolean;
 }
 
+// Room the crank progresses and resolves (created by `initialize`)
+export const DEFAULT_ROOM_ID = 0;
+
+// Matches ROUND_RESULT_RETENTION: round results can be closed 30 days after resolution
+export const ROUND_RESULT_RETENTION_SECONDS = 30 * 86_400;
+
 // PDA seeds
 export const PDA_SEEDS = {
   GAME_CONFIG: Buffer.from("game_config"),
+  ROOM: Buffer.from("room"),
   GAME_ROUND: Buffer.from("game_round"),
   VAULT: Buffer.from("vault"),
+  ROUND_RESULT: Buffer.from("round_result"),
+  HOUSE_VAULT: Buffer.from("house_vault"),
+  JACKPOT: Buffer.from("jackpot"),
+  PLAYER_STATS: Buffer.from("player_stats"),
+  REFERRAL: Buffer.from("referral"),
+  REFERRER: Buffer.from("referrer"),
+  RAKEBACK_RESERVE: Buffer.from("rakeback_reserve"),
+  PLAYER_BALANCE: Buffer.from("player_balance"),
 } as const;
 
 // Transaction types for logging (simplified for small games MVP)
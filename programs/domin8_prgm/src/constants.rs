@@ -1,18 +1,106 @@
-/// Program constants and configuration
+//! Program constants and configuration
 
 /// Game constraints
 pub const MAX_PLAYERS: usize = 64;
+pub const MAX_PRIZE_PLACES: usize = 5; // Longest supported prize split table
+pub const DEFAULT_MIN_PLAYERS: u8 = 2;
+pub const DEFAULT_MAX_WINDOW_EXTENSIONS: u8 = 3; // Waiting windows added before an under-filled round is refunded
+pub const DEFAULT_LATE_BET_WINDOW: i64 = 0; // Soft close disabled until configured
+pub const ROUND_RESULT_RETENTION: i64 = 30 * 86_400; // Results stay open 30 days for replays and champion mints
 
 /// Financial constants
 pub const MIN_BET_LAMPORTS: u64 = 10_000_000; // 0.01 SOL
 pub const HOUSE_FEE_BASIS_POINTS: u16 = 500; // 5%
+pub const BASIS_POINTS_DENOMINATOR: u16 = 10_000; // 100%
+pub const MAX_FEE_TIERS: usize = 4;
+pub const MAX_FEE_RECIPIENTS: usize = 5;
+
+/// Room created by `initialize`
+pub const DEFAULT_ROOM_ID: u64 = 0;
+pub const DEFAULT_MAX_BET: u64 = 0; // No maximum
+
+/// Private rooms
+pub const MIN_PRIVATE_ROOM_ID: u64 = 1 << 32; // Room ids below this are reserved for the authority
+pub const MAX_ROOM_ALLOWLIST: usize = 32;
+pub const DEFAULT_CREATOR_FEE_SHARE_BPS: u16 = 0; // Creators earn nothing until configured
+pub const JOIN_CODE_DOMAIN: &[u8] = b"domin8_join_code";
+
+/// Solo-vs-house defaults (solo mode starts disabled)
+pub const DEFAULT_SOLO_WIN_PROBABILITY_BPS: u16 = 5_000; // 50% chance for the solo player
+pub const DEFAULT_SOLO_HOUSE_MATCH_BPS: u16 = 10_000; // House matches 1:1
+pub const DEFAULT_HOUSE_MAX_EXPOSURE_BPS: u16 = 500; // At most 5% of the bankroll per round
+
+/// Liquidity-provider pool defaults
+pub const LP_SHARE_DECIMALS: u8 = 9; // Same precision as SOL
+pub const DEFAULT_LP_FEE_SHARE_BPS: u16 = 0; // House fee goes fully to the treasury until configured
+pub const DEFAULT_LP_WITHDRAWAL_COOLDOWN: i64 = 86_400; // 24 hours
+
+/// Tournament limits
+pub const MAX_TOURNAMENT_ENTRANTS: usize = MAX_PLAYERS;
+pub const MAX_TOURNAMENT_ROUNDS: u8 = 100;
+pub const TOURNAMENT_STALL_TIMEOUT: i64 = 86_400; // Active tournaments can be cancelled after a day without a scored round
+
+/// Champion NFTs (Metaplex Token Metadata)
+pub const CHAMPION_NFT_MINT_FEE: u64 = 10_000_000; // 0.01 SOL to the treasury
+pub const CHAMPION_ROYALTY_BASIS_POINTS: u16 = 250; // 2.5% on secondary sales
+pub const CHAMPION_COLLECTION_NAME: &str = "Royal Rumble Champions";
+pub const CHAMPION_NFT_NAME: &str = "Royal Rumble Champion";
+pub const CHAMPION_NFT_SYMBOL: &str = "RRC";
+pub const MAX_NFT_URI_LENGTH: usize = 200; // Token Metadata limit
+pub const MAX_CHAMPION_BASE_URI_LENGTH: usize = 150; // Leaves room for "/{room_id}/{round_id}.json"
+pub const NFT_BONUS_BPS_PER_NFT: u16 = 10; // Each champion NFT adds 0.1% to the winner's payout
+pub const DEFAULT_MAX_NFT_BONUS_BPS: u16 = 100; // Capped at 1% (10 NFTs)
+
+/// Referrals
+pub const DEFAULT_REFERRAL_FEE_SHARE_BPS: u16 = 0; // Referrers earn nothing until configured
+
+/// Rakeback
+pub const MAX_RAKEBACK_BPS: u16 = 5_000; // At most half of the house fee a player generated
+
+/// Bet withdrawal before the round locks
+pub const DEFAULT_BET_WITHDRAWAL_PENALTY_BPS: u16 = 0; // Free until configured
+pub const MAX_BET_WITHDRAWAL_PENALTY_BPS: u16 = 2_000; // At most 20% of the withdrawn stake
+
+/// Game Coins (internal balance)
+pub const DEFAULT_COINS_PER_SOL: u64 = 1_000; // 1 SOL = 1000 Game Coins
+pub const COIN_WITHDRAWAL_FEE_BPS: u16 = 50; // 0.5% of each withdrawal to the treasury
+pub const MIN_COIN_WITHDRAWAL_FEE: u64 = 1_000_000; // 0.001 SOL
+
+/// Session keys
+pub const MAX_SESSION_DURATION: i64 = 7 * 86_400; // Sessions last at most a week
+
+/// Seasons
+pub const MAX_SEASON_LEADERBOARD: usize = 20;
+
+/// Progressive jackpot limits
+pub const MAX_JACKPOT_CONTRIBUTION_BPS: u16 = 1_000; // At most 10% of a pot
 
 /// PDA seeds for deterministic account derivation
 pub const GAME_CONFIG_SEED: &[u8] = b"game_config";
 pub const GAME_ROUND_SEED: &[u8] = b"game_round";
+pub const ROOM_SEED: &[u8] = b"room";
 pub const VAULT_SEED: &[u8] = b"vault";
+pub const VAULT_TOKEN_SEED: &[u8] = b"vault_token";
+pub const ROUND_RESULT_SEED: &[u8] = b"round_result";
+pub const HOUSE_VAULT_SEED: &[u8] = b"house_vault";
+pub const LP_MINT_SEED: &[u8] = b"lp_mint";
+pub const LP_WITHDRAWAL_SEED: &[u8] = b"lp_withdrawal";
+pub const JACKPOT_SEED: &[u8] = b"jackpot";
+pub const TOURNAMENT_SEED: &[u8] = b"tournament";
+pub const NFT_AUTHORITY_SEED: &[u8] = b"nft_authority";
+pub const CHAMPION_COLLECTION_SEED: &[u8] = b"champion_collection";
+pub const CHAMPION_MINT_SEED: &[u8] = b"champion_mint";
+pub const CHAMPION_NFT_SEED: &[u8] = b"champion_nft";
+pub const PLAYER_STATS_SEED: &[u8] = b"player_stats";
+pub const SEASON_SEED: &[u8] = b"season";
+pub const SEASON_STATS_SEED: &[u8] = b"season_stats";
+pub const REFERRAL_SEED: &[u8] = b"referral";
+pub const REFERRER_SEED: &[u8] = b"referrer";
+pub const RAKEBACK_RESERVE_SEED: &[u8] = b"rakeback_reserve";
+pub const PLAYER_BALANCE_SEED: &[u8] = b"player_balance";
+pub const SESSION_TOKEN_SEED: &[u8] = b"session_token";
 
-/// Default game durations (in seconds)
+// Default game durations (in seconds)
 
 // Small games (2-64 players)
 pub const DEFAULT_SMALL_GAME_WAITING_DURATION: u64 = 30;
@@ -22,8 +110,8 @@ pub const DEFAULT_SMALL_GAME_RESOLVING_DURATION: u64 = 15;
 
 
 
-/// Account space calculations for rent exemption
-/// These constants are used to determine the exact amount of SOL needed for rent exemption
+// Account space calculations for rent exemption
+// These constants are used to determine the exact amount of SOL needed for rent exemption
 
 /// GameConfig account space: ~146 bytes
 /// - 8 (discriminator) + 32 (authority) + 32 (treasury) + 2 (house_fee) + 8 (min_bet)
@@ -96,4 +96,206 @@ pub enum Domin8Error {
 
     #[msg("Arithmetic overflow occurred")]
     ArithmeticOverflow,
-}
\ No newline at end of file
+
+    #[msg("Prize split must have 1-5 non-zero places summing to 10,000 basis points")]
+    InvalidPrizeSplit,
+
+    // Solo-vs-house errors
+    #[msg("House vault account is required for solo rounds")]
+    HouseVaultRequired,
+
+    #[msg("House match exceeds the bankroll exposure limit")]
+    HouseExposureExceeded,
+
+    #[msg("Invalid solo mode odds configuration")]
+    InvalidSoloOdds,
+
+    #[msg("Withdrawal would leave the house vault below rent exemption")]
+    InsufficientBankroll,
+
+    // Liquidity-provider pool errors
+    #[msg("House vault has outstanding LP shares")]
+    LpSharesOutstanding,
+
+    #[msg("Invalid LP share amount")]
+    InvalidShareAmount,
+
+    #[msg("LP withdrawal is still in its cooldown period")]
+    WithdrawalCooldownActive,
+
+    #[msg("Invalid LP pool configuration")]
+    InvalidLpConfig,
+
+    #[msg("Invalid jackpot configuration")]
+    InvalidJackpotConfig,
+
+    // SPL token pool errors
+    #[msg("Bet mint does not match the game configuration")]
+    InvalidBetMint,
+
+    #[msg("Token accounts are required for SPL token pools")]
+    TokenAccountsRequired,
+
+    #[msg("Invalid room configuration")]
+    InvalidRoomConfig,
+
+    // Private room errors
+    #[msg("Wallet is not allowed to join this room")]
+    RoomAccessDenied,
+
+    #[msg("Invalid private room access settings")]
+    InvalidRoomAccess,
+
+    #[msg("Room creator account not provided in remaining accounts")]
+    InvalidRoomCreatorAccount,
+
+    // Tournament errors
+    #[msg("Invalid tournament configuration")]
+    InvalidTournamentConfig,
+
+    #[msg("Tournament account does not match the game round")]
+    InvalidTournament,
+
+    #[msg("Tournament is not accepting registrations")]
+    TournamentRegistrationClosed,
+
+    #[msg("Tournament is full")]
+    TournamentFull,
+
+    #[msg("Wallet is already registered for this tournament")]
+    AlreadyRegistered,
+
+    #[msg("Wallet is not registered for this tournament")]
+    NotTournamentEntrant,
+
+    #[msg("Tournament has not finished")]
+    TournamentNotFinished,
+
+    // Champion NFT errors
+    #[msg("Only the round winner can mint its champion NFT")]
+    NotRoundWinner,
+
+    #[msg("NFT metadata URI is too long")]
+    InvalidNftUri,
+
+    #[msg("Invalid NFT bonus configuration")]
+    InvalidNftBonusConfig,
+
+    // Season errors
+    #[msg("Invalid season configuration")]
+    InvalidSeasonConfig,
+
+    #[msg("Season is not active")]
+    SeasonNotActive,
+
+    #[msg("Season has not ended")]
+    SeasonNotEnded,
+
+    #[msg("Season has already been finalized")]
+    SeasonAlreadyFinalized,
+
+    // Referral errors
+    #[msg("Players cannot refer themselves")]
+    SelfReferral,
+
+    #[msg("Referrer cannot change after the first bet")]
+    ReferralLocked,
+
+    #[msg("Invalid referral configuration")]
+    InvalidReferralConfig,
+
+    #[msg("Nothing to claim")]
+    NothingToClaim,
+
+    // Rakeback errors
+    #[msg("Invalid rakeback configuration")]
+    InvalidRakebackConfig,
+
+    // Game Coins errors
+    #[msg("Invalid Game Coins rate")]
+    InvalidCoinRate,
+
+    #[msg("Insufficient Game Coins balance")]
+    InsufficientBalance,
+
+    #[msg("Withdrawal does not cover the withdrawal fee")]
+    WithdrawalTooSmall,
+
+    #[msg("Game Coins can only be bet in native SOL rooms")]
+    CoinBetsSolOnly,
+
+    // Session key errors
+    #[msg("Invalid session configuration")]
+    InvalidSessionConfig,
+
+    #[msg("Session has expired")]
+    SessionExpired,
+
+    #[msg("Bet exceeds the session's remaining spending limit")]
+    SessionLimitExceeded,
+
+    // Sponsored bet errors
+    #[msg("Invalid relayer configuration")]
+    InvalidRelayerConfig,
+
+    // Bet withdrawal errors
+    #[msg("Invalid bet withdrawal penalty")]
+    InvalidWithdrawalPenalty,
+
+    // Minimum participant errors
+    #[msg("Invalid minimum player configuration")]
+    InvalidMinPlayersConfig,
+
+    #[msg("Waiting window has not ended")]
+    WaitingWindowOpen,
+
+    // Soft close errors
+    #[msg("Invalid late bet configuration")]
+    InvalidLateBetConfig,
+
+    // Wallet wager cap errors
+    #[msg("Invalid wallet wager cap configuration")]
+    InvalidWalletCapConfig,
+
+    #[msg("Bet would put the wallet above its maximum share of the pot; see logs for the maximum top-up")]
+    WalletPotShareExceeded,
+
+    // House fee tier errors
+    #[msg("Invalid house fee tiers")]
+    InvalidFeeTiers,
+
+    // Fee split errors
+    #[msg("Invalid fee split")]
+    InvalidFeeSplit,
+
+    #[msg("Fee split recipient account missing or invalid")]
+    InvalidFeeRecipient,
+
+    // Fee sweep errors
+    #[msg("No accrued fees to sweep")]
+    NoFeesToSweep,
+
+    // LP deposit errors
+    #[msg("House vault holds authority capital; withdraw it before LPs can deposit")]
+    HouseCapitalOutstanding,
+
+    // Bet refund errors
+    #[msg("Player balance account is required to return a Game Coins stake")]
+    PlayerBalanceRequired,
+
+    // Round result errors
+    #[msg("Round result is still within its retention period")]
+    RoundResultRetained,
+
+    // Room bet limit errors
+    #[msg("Bet amount is above the room maximum")]
+    BetTooLarge,
+
+    // Tournament cancellation errors
+    #[msg("Tournament can only be cancelled during registration or after stalling")]
+    TournamentNotCancellable,
+
+    // Champion NFT metadata errors
+    #[msg("Champion NFT base URI and character count are not configured")]
+    ChampionNftNotConfigured,
+}
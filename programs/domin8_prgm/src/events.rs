@@ -0,0 +1,12 @@
+//! Program events for off-chain indexers
+
+use anchor_lang::prelude::*;
+
+/// A room's accrued house fees were sent to the treasury (or the fee split recipients)
+#[event]
+pub struct FeesSwept {
+    pub room_id: u64,
+    pub amount: u64,
+    pub remaining_accrued: u64,  // Fees left in the vault because of its rent-exempt minimum
+    pub swept_at: i64,
+}
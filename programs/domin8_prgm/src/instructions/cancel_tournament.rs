@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameConfig, GameRound, Tournament, TournamentStatus};
+use crate::errors::Domin8Error;
+use crate::constants::{GAME_CONFIG_SEED, GAME_ROUND_SEED, TOURNAMENT_SEED};
+
+#[derive(Accounts)]
+pub struct CancelTournament<'info> {
+    #[account(
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    /// Prizes are only paid out once the tournament finishes, so every buy-in is still held
+    #[account(
+        mut,
+        seeds = [TOURNAMENT_SEED, tournament.tournament_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// The room's round, released from the tournament
+    #[account(
+        mut,
+        seeds = [GAME_ROUND_SEED, tournament.room_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub game_round: Account<'info, GameRound>,
+
+    #[account(
+        constraint = authority.key() == config.authority @ Domin8Error::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Cancel a tournament during registration, or once it has stalled without a
+/// scored round, and refund every entrant's buy-in. Points already earned are
+/// forfeited. All entrants' wallets must be passed in remaining_accounts.
+pub fn cancel_tournament(ctx: Context<CancelTournament>) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    require!(
+        tournament.can_cancel(Clock::get()?.unix_timestamp),
+        Domin8Error::TournamentNotCancellable
+    );
+
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(tournament.to_account_info().data_len());
+    let available = tournament.to_account_info().lamports().saturating_sub(rent_exempt_minimum);
+    require!(available >= tournament.prize_pool, Domin8Error::InsufficientFunds);
+
+    let buy_in = tournament.buy_in;
+    if buy_in > 0 {
+        for standing in tournament.standings.iter() {
+            let account = ctx.remaining_accounts
+                .iter()
+                .find(|account| account.key() == standing.wallet)
+                .ok_or(Domin8Error::PlayerNotFound)?;
+
+            // Direct lamport transfer (tournament is program-owned)
+            **tournament.to_account_info().try_borrow_mut_lamports()? -= buy_in;
+            **account.try_borrow_mut_lamports()? += buy_in;
+        }
+    }
+
+    tournament.prize_pool = 0;
+    tournament.status = TournamentStatus::Cancelled;
+
+    let game_round = &mut ctx.accounts.game_round;
+    if game_round.tournament == tournament.key() {
+        game_round.tournament = Pubkey::default();
+    }
+
+    msg!("Tournament {} cancelled, {} entrants refunded {} each",
+         tournament.tournament_id, tournament.standings.len(), buy_in);
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::state::{PlayerStats, RakebackReserve};
+use crate::errors::Domin8Error;
+use crate::constants::{PLAYER_STATS_SEED, RAKEBACK_RESERVE_SEED};
+
+#[derive(Accounts)]
+pub struct ClaimRakeback<'info> {
+    #[account(
+        mut,
+        seeds = [RAKEBACK_RESERVE_SEED],
+        bump
+    )]
+    pub rakeback_reserve: Account<'info, RakebackReserve>,
+
+    #[account(
+        mut,
+        seeds = [PLAYER_STATS_SEED, player.key().as_ref()],
+        bump
+    )]
+    pub player_stats: Account<'info, PlayerStats>,
+
+    #[account(mut)]
+    pub player: Signer<'info>,
+}
+
+/// Redeem loyalty points for rakeback. If the reserve cannot cover every
+/// point, the affordable part is redeemed and the rest stays on the account.
+pub fn claim_rakeback(ctx: Context<ClaimRakeback>) -> Result<()> {
+    let rakeback_reserve = &mut ctx.accounts.rakeback_reserve;
+    let player_stats = &mut ctx.accounts.player_stats;
+
+    let reserve_info = rakeback_reserve.to_account_info();
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(reserve_info.data_len());
+    let available = reserve_info.lamports().saturating_sub(rent_exempt_minimum);
+
+    let points = rakeback_reserve.redeemable_points(player_stats.loyalty_points, available);
+    let amount = rakeback_reserve.calculate_rakeback(points);
+    require!(amount > 0, Domin8Error::NothingToClaim);
+
+    // Direct lamport transfer (rakeback reserve is program-owned)
+    **reserve_info.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.player.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    player_stats.loyalty_points = player_stats.loyalty_points.saturating_sub(points);
+    player_stats.rakeback_claimed = player_stats.rakeback_claimed.saturating_add(amount);
+    rakeback_reserve.total_claimed = rakeback_reserve.total_claimed.saturating_add(amount);
+
+    msg!("Rakeback claimed: {} lamports for {} points to {}", amount, points, player_stats.player);
+
+    Ok(())
+}
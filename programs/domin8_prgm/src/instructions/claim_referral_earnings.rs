@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::state::ReferrerAccount;
+use crate::errors::Domin8Error;
+use crate::constants::REFERRER_SEED;
+
+#[derive(Accounts)]
+pub struct ClaimReferralEarnings<'info> {
+    #[account(
+        mut,
+        seeds = [REFERRER_SEED, referrer.key().as_ref()],
+        bump,
+        has_one = referrer @ Domin8Error::Unauthorized
+    )]
+    pub referrer_account: Account<'info, ReferrerAccount>,
+
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+}
+
+/// Withdraw accumulated referral earnings
+pub fn claim_referral_earnings(ctx: Context<ClaimReferralEarnings>) -> Result<()> {
+    let amount = ctx.accounts.referrer_account.claimable;
+    require!(amount > 0, Domin8Error::NothingToClaim);
+
+    // Direct lamport transfer (referrer account is program-owned)
+    **ctx.accounts.referrer_account.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.referrer.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    let referrer_account = &mut ctx.accounts.referrer_account;
+    referrer_account.claimable = 0;
+    referrer_account.total_claimed = referrer_account.total_claimed.saturating_add(amount);
+
+    msg!("Referral earnings claimed: {} lamports to {}", amount, referrer_account.referrer);
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameConfig, RoundResult};
+use crate::errors::Domin8Error;
+use crate::constants::*;
+
+#[derive(Accounts)]
+pub struct CloseRoundResult<'info> {
+    #[account(
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    /// Closed once its retention period has passed; rent goes back to the authority
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            ROUND_RESULT_SEED,
+            round_result.room_id.to_le_bytes().as_ref(),
+            round_result.round_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub round_result: Account<'info, RoundResult>,
+
+    /// The crank authority that paid the result's rent
+    #[account(
+        mut,
+        constraint = authority.key() == config.authority @ Domin8Error::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Close a round result older than `ROUND_RESULT_RETENTION` and reclaim its rent.
+/// The champion NFT for the round can no longer be minted afterwards.
+pub fn close_round_result(ctx: Context<CloseRoundResult>) -> Result<()> {
+    let round_result = &ctx.accounts.round_result;
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now >= round_result.resolved_at.saturating_add(ROUND_RESULT_RETENTION),
+        Domin8Error::RoundResultRetained
+    );
+
+    msg!("Round result closed for room {} round {}", round_result.room_id, round_result.round_id);
+
+    Ok(())
+}
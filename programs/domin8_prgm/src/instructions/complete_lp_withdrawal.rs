@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::state::{HouseVault, LpWithdrawal};
+use crate::errors::Domin8Error;
+use crate::constants::{HOUSE_VAULT_SEED, LP_WITHDRAWAL_SEED};
+
+#[derive(Accounts)]
+pub struct CompleteLpWithdrawal<'info> {
+    #[account(
+        mut,
+        seeds = [HOUSE_VAULT_SEED],
+        bump
+    )]
+    pub house_vault: Account<'info, HouseVault>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [LP_WITHDRAWAL_SEED, owner.key().as_ref()],
+        bump,
+        has_one = owner @ Domin8Error::Unauthorized
+    )]
+    pub lp_withdrawal: Account<'info, LpWithdrawal>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+/// Pay out a pending LP withdrawal once its cooldown has elapsed
+pub fn complete_lp_withdrawal(ctx: Context<CompleteLpWithdrawal>) -> Result<()> {
+    let clock = Clock::get()?;
+    let lp_withdrawal = &ctx.accounts.lp_withdrawal;
+
+    require!(
+        clock.unix_timestamp >= lp_withdrawal.unlock_at,
+        Domin8Error::WithdrawalCooldownActive
+    );
+
+    let lamports = lp_withdrawal.lamports;
+
+    // Direct lamport transfer (house vault is program-owned)
+    **ctx.accounts.house_vault.to_account_info().try_borrow_mut_lamports()? -= lamports;
+    **ctx.accounts.owner.to_account_info().try_borrow_mut_lamports()? += lamports;
+
+    let house_vault = &mut ctx.accounts.house_vault;
+    house_vault.pending_withdrawals = house_vault.pending_withdrawals.saturating_sub(lamports);
+
+    msg!("LP withdrawal completed: {} lamports to {}", lamports, ctx.accounts.owner.key());
+
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameRound, GameDurationConfig, GameMode, GameStatus, Room, RoomAccess};
+use crate::errors::Domin8Error;
+use crate::constants::*;
+use super::create_room::validate_room_params;
+
+#[derive(Accounts)]
+#[instruction(room_id: u64)]
+pub struct CreatePrivateRoom<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = Room::LEN,
+        seeds = [ROOM_SEED, room_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub room: Account<'info, Room>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = GameRound::LEN,
+        seeds = [GAME_ROUND_SEED, room_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub game_round: Account<'info, GameRound>,
+
+    /// Anyone can open a private room; the creator pays rent and earns a share of its house fee
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Open a native SOL room restricted to an allowlist or a join code.
+/// The house fee is the program default; the creator earns `GameConfig::creator_fee_share_bps` of it.
+#[allow(clippy::too_many_arguments)]
+pub fn create_private_room(
+    ctx: Context<CreatePrivateRoom>,
+    room_id: u64,
+    min_bet: u64,
+    max_bet: u64,
+    waiting_phase_duration: u64,
+    game_mode: GameMode,
+    access: RoomAccess,
+    join_code_hash: [u8; 32],
+    allowlist: Vec<Pubkey>,
+) -> Result<()> {
+    require!(room_id >= MIN_PRIVATE_ROOM_ID, Domin8Error::InvalidRoomConfig);
+    validate_room_params(min_bet, max_bet, HOUSE_FEE_BASIS_POINTS)?;
+    require!(
+        Room::is_valid_access(access, &join_code_hash, &allowlist),
+        Domin8Error::InvalidRoomAccess
+    );
+
+    let room = &mut ctx.accounts.room;
+    room.room_id = room_id;
+    room.min_bet = min_bet;
+    room.max_bet = max_bet;
+    room.house_fee_basis_points = HOUSE_FEE_BASIS_POINTS;
+    room.duration_config = GameDurationConfig { waiting_phase_duration };
+    room.game_mode = game_mode;
+    room.prize_split_basis_points = [0u16; MAX_PRIZE_PLACES];
+    room.prize_split_basis_points[0] = BASIS_POINTS_DENOMINATOR;
+    room.bet_mint = Pubkey::default();
+    room.bet_mint_decimals = 9;
+    room.creator = ctx.accounts.creator.key();
+    room.access = access;
+    room.join_code_hash = join_code_hash;
+    room.allowlist = allowlist;
+
+    // Remaining round fields start zeroed
+    let game_round = &mut ctx.accounts.game_round;
+    game_round.room_id = room_id;
+    game_round.round_id = 0;
+    game_round.status = GameStatus::Idle;
+
+    msg!("Private room {} created by {}: {:?} access, bets {}-{}",
+         room_id, room.creator, access, min_bet, max_bet);
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameConfig, GameRound, GameDurationConfig, GameMode, GameStatus, Room, RoomAccess};
+use crate::errors::Domin8Error;
+use crate::constants::*;
+
+#[derive(Accounts)]
+#[instruction(room_id: u64)]
+pub struct CreateRoom<'info> {
+    #[account(
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Room::LEN,
+        seeds = [ROOM_SEED, room_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub room: Account<'info, Room>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = GameRound::LEN,
+        seeds = [GAME_ROUND_SEED, room_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub game_round: Account<'info, GameRound>,
+
+    #[account(
+        mut,
+        constraint = authority.key() == config.authority @ Domin8Error::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Open a new public native SOL room with its own limits, fee, duration and game mode.
+/// Use `set_bet_mint` to turn it into a token room and `set_prize_split` for top-N payouts.
+/// Room ids from MIN_PRIVATE_ROOM_ID up are left for `create_private_room`.
+pub fn create_room(
+    ctx: Context<CreateRoom>,
+    room_id: u64,
+    min_bet: u64,
+    max_bet: u64,
+    house_fee_basis_points: u16,
+    waiting_phase_duration: u64,
+    game_mode: GameMode,
+) -> Result<()> {
+    require!(room_id < MIN_PRIVATE_ROOM_ID, Domin8Error::InvalidRoomConfig);
+    validate_room_params(min_bet, max_bet, house_fee_basis_points)?;
+
+    let room = &mut ctx.accounts.room;
+    room.room_id = room_id;
+    room.min_bet = min_bet;
+    room.max_bet = max_bet;
+    room.house_fee_basis_points = house_fee_basis_points;
+    room.duration_config = GameDurationConfig { waiting_phase_duration };
+    room.game_mode = game_mode;
+    room.prize_split_basis_points = [0u16; MAX_PRIZE_PLACES];
+    room.prize_split_basis_points[0] = BASIS_POINTS_DENOMINATOR;
+    room.bet_mint = Pubkey::default();
+    room.bet_mint_decimals = 9;
+    room.creator = ctx.accounts.authority.key();
+    room.access = RoomAccess::Public;
+    room.join_code_hash = [0u8; 32];
+    room.allowlist = Vec::new();
+
+    // Remaining round fields start zeroed
+    let game_round = &mut ctx.accounts.game_round;
+    game_round.room_id = room_id;
+    game_round.round_id = 0;
+    game_round.status = GameStatus::Idle;
+
+    msg!("Room {} created: bets {}-{}, fee {} bps, {}s waiting, {:?}",
+         room_id, min_bet, max_bet, house_fee_basis_points, waiting_phase_duration, game_mode);
+
+    Ok(())
+}
+
+/// Shared validation for room economics
+pub(crate) fn validate_room_params(min_bet: u64, max_bet: u64, house_fee_basis_points: u16) -> Result<()> {
+    require!(min_bet > 0, Domin8Error::InvalidRoomConfig);
+    require!(max_bet == 0 || max_bet >= min_bet, Domin8Error::InvalidRoomConfig);
+    require!(house_fee_basis_points < BASIS_POINTS_DENOMINATOR, Domin8Error::InvalidRoomConfig);
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameConfig, PointsFormula, Room, Season};
+use crate::errors::Domin8Error;
+use crate::constants::*;
+
+#[derive(Accounts)]
+#[instruction(season_id: u64)]
+pub struct CreateSeason<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Season::LEN,
+        seeds = [SEASON_SEED, season_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub season: Account<'info, Season>,
+
+    /// The season currently funded from house fees; required unless there is none
+    #[account(
+        constraint = previous_season.key() == config.current_season @ Domin8Error::InvalidSeasonConfig
+    )]
+    pub previous_season: Option<Account<'info, Season>>,
+
+    #[account(
+        mut,
+        constraint = authority.key() == config.authority @ Domin8Error::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create a season and make it the one funded from house fees.
+/// The season it replaces must have been finalized or have ended.
+pub fn create_season(
+    ctx: Context<CreateSeason>,
+    season_id: u64,
+    start_ts: i64,
+    end_ts: i64,
+    fee_share_bps: u16,
+    points_formula: PointsFormula,
+    prize_split_basis_points: Vec<u16>,
+) -> Result<()> {
+    require!(end_ts > start_ts, Domin8Error::InvalidSeasonConfig);
+    require!(fee_share_bps <= BASIS_POINTS_DENOMINATOR, Domin8Error::InvalidSeasonConfig);
+    require!(
+        Room::is_valid_prize_split(&prize_split_basis_points),
+        Domin8Error::InvalidPrizeSplit
+    );
+
+    if ctx.accounts.config.current_season != Pubkey::default() {
+        let previous_season = ctx.accounts.previous_season
+            .as_ref()
+            .ok_or(Domin8Error::InvalidSeasonConfig)?;
+        require!(
+            previous_season.finalized || Clock::get()?.unix_timestamp > previous_season.end_ts,
+            Domin8Error::SeasonNotEnded
+        );
+    }
+
+    let mut table = [0u16; MAX_PRIZE_PLACES];
+    table[..prize_split_basis_points.len()].copy_from_slice(&prize_split_basis_points);
+
+    let season = &mut ctx.accounts.season;
+    season.season_id = season_id;
+    season.start_ts = start_ts;
+    season.end_ts = end_ts;
+    season.fee_share_bps = fee_share_bps;
+    season.points_formula = points_formula;
+    season.prize_split_basis_points = table;
+    season.prize_pool = 0;
+    season.finalized = false;
+    season.leaderboard = Vec::new();
+
+    ctx.accounts.config.current_season = season.key();
+
+    msg!("Season {} created: {} to {}, {} bps of house fees",
+         season_id, start_ts, end_ts, fee_share_bps);
+
+    Ok(())
+}
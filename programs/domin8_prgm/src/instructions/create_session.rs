@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::state::SessionToken;
+use crate::errors::Domin8Error;
+use crate::constants::{MAX_SESSION_DURATION, SESSION_TOKEN_SEED};
+
+#[derive(Accounts)]
+#[instruction(session_key: Pubkey)]
+pub struct CreateSession<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = SessionToken::LEN,
+        seeds = [SESSION_TOKEN_SEED, owner.key().as_ref(), session_key.as_ref()],
+        bump
+    )]
+    pub session_token: Account<'info, SessionToken>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Authorize `session_key` to bet up to `spending_limit` lamports from the
+/// owner's Game Coins balance until `expires_at`
+pub fn create_session(
+    ctx: Context<CreateSession>,
+    session_key: Pubkey,
+    spending_limit: u64,
+    expires_at: i64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let owner = ctx.accounts.owner.key();
+    require!(
+        session_key != owner
+            && spending_limit > 0
+            && expires_at > now
+            && expires_at.saturating_sub(now) <= MAX_SESSION_DURATION,
+        Domin8Error::InvalidSessionConfig
+    );
+
+    let session_token = &mut ctx.accounts.session_token;
+    session_token.owner = owner;
+    session_token.session_key = session_key;
+    session_token.spending_limit = spending_limit;
+    session_token.spent = 0;
+    session_token.expires_at = expires_at;
+    session_token.created_at = now;
+
+    msg!("Session {} for {}: {} lamports until {}", session_key, owner, spending_limit, expires_at);
+
+    Ok(())
+}
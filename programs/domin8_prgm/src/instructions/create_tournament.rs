@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameConfig, GameRound, GameStatus, Room, Tournament, TournamentStatus};
+use crate::errors::Domin8Error;
+use crate::constants::*;
+
+#[derive(Accounts)]
+#[instruction(tournament_id: u64)]
+pub struct CreateTournament<'info> {
+    #[account(
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        seeds = [ROOM_SEED, room.room_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub room: Account<'info, Room>,
+
+    /// The room's round is linked to the tournament until its final round resolves
+    #[account(
+        mut,
+        seeds = [GAME_ROUND_SEED, room.room_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = game_round.status == GameStatus::Idle @ Domin8Error::InvalidGameStatus,
+        constraint = !game_round.is_tournament_round() @ Domin8Error::InvalidTournamentConfig
+    )]
+    pub game_round: Account<'info, GameRound>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Tournament::LEN,
+        seeds = [TOURNAMENT_SEED, tournament_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(
+        mut,
+        constraint = authority.key() == config.authority @ Domin8Error::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create a tournament over the room's next `total_rounds` rounds.
+/// Only registered entrants can bet in the room until the tournament ends.
+pub fn create_tournament(
+    ctx: Context<CreateTournament>,
+    tournament_id: u64,
+    buy_in: u64,
+    total_rounds: u8,
+    prize_split_basis_points: Vec<u16>,
+) -> Result<()> {
+    require!(
+        total_rounds > 0 && total_rounds <= MAX_TOURNAMENT_ROUNDS,
+        Domin8Error::InvalidTournamentConfig
+    );
+    require!(
+        Room::is_valid_prize_split(&prize_split_basis_points),
+        Domin8Error::InvalidPrizeSplit
+    );
+
+    let mut table = [0u16; MAX_PRIZE_PLACES];
+    table[..prize_split_basis_points.len()].copy_from_slice(&prize_split_basis_points);
+
+    let tournament = &mut ctx.accounts.tournament;
+    tournament.tournament_id = tournament_id;
+    tournament.room_id = ctx.accounts.room.room_id;
+    tournament.buy_in = buy_in;
+    tournament.total_rounds = total_rounds;
+    tournament.rounds_played = 0;
+    tournament.status = TournamentStatus::Registration;
+    tournament.prize_pool = 0;
+    tournament.prize_split_basis_points = table;
+    tournament.standings = Vec::new();
+    tournament.last_activity_at = Clock::get()?.unix_timestamp;
+
+    ctx.accounts.game_round.tournament = tournament.key();
+
+    msg!("Tournament {} created in room {}: {} rounds, {} lamport buy-in",
+         tournament_id, tournament.room_id, total_rounds, buy_in);
+
+    Ok(())
+}
@@ -1,51 +1,223 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
-use crate::state::{GameRound, GameStatus, PlayerEntry};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::{GameConfig, GameRound, GameStatus, PlayerEntry, PlayerStats, Room, Tournament, TournamentStatus};
 use crate::constants::*;
 use crate::errors::Domin8Error;
 
 #[derive(Accounts)]
 pub struct DepositBet<'info> {
+    #[account(
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+    
+    #[account(
+        seeds = [ROOM_SEED, room.room_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub room: Account<'info, Room>,
+    
     #[account(
         mut,
-        seeds = [GAME_ROUND_SEED],
+        seeds = [GAME_ROUND_SEED, room.room_id.to_le_bytes().as_ref()],
         bump
     )]
     pub game_round: Account<'info, GameRound>,
     
-    /// CHECK: This is the vault PDA that holds game funds
+    /// CHECK: This is the room's vault PDA that holds game funds
     #[account(
         mut,
-        seeds = [VAULT_SEED],
+        seeds = [VAULT_SEED, room.room_id.to_le_bytes().as_ref()],
         bump
     )]
     pub vault: UncheckedAccount<'info>,
     
+    /// Signs to authorize the stake, which is paid from this wallet
     #[account(mut)]
     pub player: Signer<'info>,
     
+    /// Pays rent for new accounts; the player or a relayer sponsoring the bet
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+    
+    /// Created on the player's first bet, rent paid by the fee payer
+    #[account(
+        init_if_needed,
+        payer = fee_payer,
+        space = PlayerStats::LEN,
+        seeds = [PLAYER_STATS_SEED, player.key().as_ref()],
+        bump
+    )]
+    pub player_stats: Box<Account<'info, PlayerStats>>,
+    
+    // SPL token rooms only - omit all four for native SOL bets
+    #[account(
+        address = room.bet_mint @ Domin8Error::InvalidBetMint
+    )]
+    pub bet_mint: Option<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        mut,
+        constraint = player_token_account.mint == room.bet_mint @ Domin8Error::InvalidBetMint
+    )]
+    pub player_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_TOKEN_SEED, room.room_id.to_le_bytes().as_ref(), room.bet_mint.as_ref()],
+        bump
+    )]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    
+    /// Required while the room's round counts towards a tournament
+    #[account(
+        mut,
+        constraint = tournament.key() == game_round.tournament @ Domin8Error::InvalidTournament
+    )]
+    pub tournament: Option<Account<'info, Tournament>>,
+    
     pub system_program: Program<'info, System>,
 }
 
+/// `join_code` is only checked in join-code rooms. It becomes public in the
+/// transaction, so allowlists are the stronger gate for private rooms.
+/// When the configured relayer is the fee payer, the bet counts as sponsored
+/// and the relayer is reimbursed from the house fee at resolution.
 pub fn deposit_bet(
     ctx: Context<DepositBet>,
     amount: u64,
+    join_code: Option<Vec<u8>>,
 ) -> Result<()> {
-    let game_round = &mut ctx.accounts.game_round;
     let player_key = ctx.accounts.player.key();
-    let clock = Clock::get()?;
+    validate_bet(
+        &ctx.accounts.config,
+        &ctx.accounts.room,
+        &ctx.accounts.game_round,
+        ctx.accounts.tournament.as_deref_mut(),
+        &player_key,
+        amount,
+        join_code.as_deref(),
+    )?;
+    
+    let amount = if ctx.accounts.room.is_token_pool() {
+        // Transfer tokens to the vault token account
+        let (Some(bet_mint), Some(player_token_account), Some(vault_token_account), Some(token_program)) = (
+            &ctx.accounts.bet_mint,
+            &ctx.accounts.player_token_account,
+            ctx.accounts.vault_token_account.as_mut(),
+            &ctx.accounts.token_program,
+        ) else {
+            return err!(Domin8Error::TokenAccountsRequired);
+        };
+        
+        let vault_balance_before = vault_token_account.amount;
+        token_interface::transfer_checked(
+            CpiContext::new(
+                token_program.to_account_info(),
+                TransferChecked {
+                    from: player_token_account.to_account_info(),
+                    mint: bet_mint.to_account_info(),
+                    to: vault_token_account.to_account_info(),
+                    authority: ctx.accounts.player.to_account_info(),
+                },
+            ),
+            amount,
+            bet_mint.decimals,
+        )?;
+        
+        // Credit what actually arrived (Token-2022 transfer fees are withheld from it)
+        vault_token_account.reload()?;
+        vault_token_account.amount.saturating_sub(vault_balance_before)
+    } else {
+        // Transfer SOL to vault
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.player.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        amount
+    };
+    
+    let fee_payer = ctx.accounts.fee_payer.key();
+    let sponsored = fee_payer != player_key && fee_payer == ctx.accounts.config.relayer;
     
+    record_bet(
+        &ctx.accounts.config,
+        &ctx.accounts.room,
+        &mut ctx.accounts.game_round,
+        &mut ctx.accounts.player_stats,
+        player_key,
+        amount,
+        BetSource::Wallet { sponsored },
+    )
+}
+
+/// Where a bet's funds came from, so withdrawals and refunds can send them back there
+pub(crate) enum BetSource {
+    /// Paid from the player's wallet, with the transaction fee covered by the relayer if sponsored
+    Wallet { sponsored: bool },
+    /// Paid from the player's Game Coins balance
+    Balance,
+}
+
+/// Checks shared by every way of placing a bet, run before any funds move.
+/// The first bet of a tournament round closes its registration.
+pub(crate) fn validate_bet(
+    config: &GameConfig,
+    room: &Room,
+    game_round: &GameRound,
+    tournament: Option<&mut Tournament>,
+    player_key: &Pubkey,
+    amount: u64,
+    join_code: Option<&[u8]>,
+) -> Result<()> {
     // Validate game state - must be Idle or Waiting
     require!(
         game_round.can_accept_players(),
         Domin8Error::InvalidGameStatus
     );
+    require!(
+        game_round.is_betting_open(Clock::get()?.unix_timestamp),
+        Domin8Error::BettingPhaseClosed
+    );
     
-    // Validate bet amount meets minimum requirement
+    // Private rooms only accept allowlisted wallets or holders of the join code
     require!(
-        amount >= MIN_BET_LAMPORTS,
+        room.can_join(player_key, join_code),
+        Domin8Error::RoomAccessDenied
+    );
+    
+    // Tournament rounds are limited to entrants; the first bet closes registration
+    if game_round.is_tournament_round() {
+        let tournament = tournament.ok_or(Domin8Error::InvalidTournament)?;
+        require!(
+            tournament.is_entrant(player_key),
+            Domin8Error::NotTournamentEntrant
+        );
+        if tournament.status == TournamentStatus::Registration {
+            tournament.status = TournamentStatus::Active;
+            tournament.last_activity_at = Clock::get()?.unix_timestamp;
+        }
+    }
+    
+    // Validate bet amount against the room's limits
+    require!(
+        amount >= room.min_bet,
         Domin8Error::BetTooSmall
     );
+    require!(
+        room.is_within_max_bet(amount),
+        Domin8Error::BetTooLarge
+    );
     
     // Check if we've reached maximum players
     require!(
@@ -53,29 +225,64 @@ pub fn deposit_bet(
         Domin8Error::MaxPlayersReached
     );
     
-    // Transfer SOL to vault
-    system_program::transfer(
-        CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
-            system_program::Transfer {
-                from: ctx.accounts.player.to_account_info(),
-                to: ctx.accounts.vault.to_account_info(),
-            },
-        ),
-        amount,
-    )?;
+    // Once the pot is large enough, no wallet may hold more than the configured share of it;
+    // a wallet still betting alone may put in up to the minimum pot.
+    let has_rival = game_round.players.iter().any(|p| p.wallet != *player_key);
+    let wallet_bet = game_round.find_player(player_key).map_or(0, |p| p.total_bet);
+    let max_bet = config.max_wallet_bet(wallet_bet, game_round.initial_pot, has_rival);
+    if amount > max_bet {
+        msg!("Maximum top-up for {}: {} ({} bps of the pot)",
+             player_key, max_bet, config.max_wallet_pot_share_bps);
+        return err!(Domin8Error::WalletPotShareExceeded);
+    }
+    
+    Ok(())
+}
+
+/// Add a bet that has reached the vault to the round and the player's statistics
+pub(crate) fn record_bet(
+    config: &GameConfig,
+    room: &Room,
+    game_round: &mut GameRound,
+    player_stats: &mut PlayerStats,
+    player_key: Pubkey,
+    amount: u64,
+    source: BetSource,
+) -> Result<()> {
+    let clock = Clock::get()?;
     
     // Update game state based on current status
     if game_round.status == GameStatus::Idle {
         // First player - transition to Waiting
         game_round.status = GameStatus::Waiting;
         game_round.start_timestamp = clock.unix_timestamp;
+        game_round.close_at = clock.unix_timestamp
+            .saturating_add(room.duration_config.waiting_phase_duration as i64);
         game_round.initial_pot = amount;
         
-        msg!("Game started by first player");
+        msg!("Game started by first player, betting closes at {}", game_round.close_at);
     } else {
         // Add to existing pot
         game_round.initial_pot = game_round.initial_pot.saturating_add(amount);
+        
+        let close_at = game_round.close_at;
+        game_round.apply_late_bet(
+            clock.unix_timestamp,
+            config.late_bet_window,
+            config.late_bet_extension,
+            config.max_late_bet_extension,
+        );
+        if game_round.close_at != close_at {
+            msg!("Late bet - betting now closes at {}", game_round.close_at);
+        }
+    }
+    
+    player_stats.player = player_key;
+    if player_stats.first_bet_at == 0 {
+        player_stats.first_bet_at = clock.unix_timestamp;
+    }
+    if !room.is_token_pool() {
+        player_stats.total_wagered = player_stats.total_wagered.saturating_add(amount);
     }
     
     // Find existing player or add new one
@@ -91,15 +298,43 @@ pub fn deposit_bet(
             wallet: player_key,
             total_bet: amount,
             timestamp: clock.unix_timestamp,
+            balance_bet: 0,
+            sponsored_bets: 0,
+            loyalty_points: 0,
         };
         
         game_round.players.push(player_entry);
+        player_stats.rounds_played = player_stats.rounds_played.saturating_add(1);
         
         msg!("New player joined: {}, bet: {}, total players: {}", 
              player_key, amount, game_round.players.len());
     }
     
-    msg!("Total pot: {} lamports", game_round.initial_pot);
+    // One loyalty point per lamport of house fee on the stake, at the rate of the pot it joined
+    let loyalty_points = if room.is_token_pool() {
+        0
+    } else {
+        room.calculate_stake_house_fee(config, amount, game_round.initial_pot)
+    };
+    player_stats.loyalty_points = player_stats.loyalty_points.saturating_add(loyalty_points);
+    
+    let player = game_round
+        .find_player_mut(&player_key)
+        .ok_or(Domin8Error::PlayerNotFound)?;
+    player.loyalty_points = player.loyalty_points.saturating_add(loyalty_points);
+    match source {
+        BetSource::Balance => {
+            player.balance_bet = player.balance_bet.saturating_add(amount);
+        },
+        BetSource::Wallet { sponsored: true } => {
+            player.sponsored_bets = player.sponsored_bets.saturating_add(1);
+            game_round.sponsored_bets = game_round.sponsored_bets.saturating_add(1);
+        },
+        BetSource::Wallet { sponsored: false } => {},
+    }
+
+    
+    msg!("Total pot: {}", game_round.initial_pot);
     
     Ok(())
-}
\ No newline at end of file
+}
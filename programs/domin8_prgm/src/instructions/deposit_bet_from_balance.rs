@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameConfig, GameRound, PlayerBalance, PlayerStats, Room, SessionToken, Tournament};
+use crate::constants::*;
+use crate::errors::Domin8Error;
+use super::deposit_bet::{record_bet, validate_bet, BetSource};
+
+#[derive(Accounts)]
+pub struct DepositBetFromBalance<'info> {
+    #[account(
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+    
+    #[account(
+        seeds = [ROOM_SEED, room.room_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub room: Account<'info, Room>,
+    
+    #[account(
+        mut,
+        seeds = [GAME_ROUND_SEED, room.room_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub game_round: Account<'info, GameRound>,
+    
+    /// CHECK: This is the room's vault PDA that holds game funds
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, room.room_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub vault: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [PLAYER_BALANCE_SEED, player.key().as_ref()],
+        bump
+    )]
+    pub player_balance: Account<'info, PlayerBalance>,
+    
+    /// CHECK: The betting wallet; must be the signer or have authorized it through `session_token`
+    pub player: UncheckedAccount<'info>,
+    
+    /// The player, or a session key authorized by the player
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    
+    /// Required when a session key signs instead of the player
+    #[account(
+        mut,
+        seeds = [SESSION_TOKEN_SEED, player.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub session_token: Option<Account<'info, SessionToken>>,
+    
+    /// Created on the player's first bet, rent paid by the signer
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = PlayerStats::LEN,
+        seeds = [PLAYER_STATS_SEED, player.key().as_ref()],
+        bump
+    )]
+    pub player_stats: Box<Account<'info, PlayerStats>>,
+    
+    /// Required while the room's round counts towards a tournament
+    #[account(
+        mut,
+        constraint = tournament.key() == game_round.tournament @ Domin8Error::InvalidTournament
+    )]
+    pub tournament: Option<Account<'info, Tournament>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Bet `coins` Game Coins from the player's internal balance (native SOL rooms only).
+/// Room limits apply to the lamport value of the coins. A session key can sign
+/// in the player's place within its spending limit and expiry; the funds still
+/// come from the player's balance.
+pub fn deposit_bet_from_balance(
+    ctx: Context<DepositBetFromBalance>,
+    coins: u64,
+    join_code: Option<Vec<u8>>,
+) -> Result<()> {
+    require!(
+        !ctx.accounts.room.is_token_pool(),
+        Domin8Error::CoinBetsSolOnly
+    );
+    
+    let amount = ctx.accounts.config
+        .coins_to_lamports(coins)
+        .ok_or(Domin8Error::InvalidCoinRate)?;
+    require!(
+        amount <= ctx.accounts.player_balance.balance,
+        Domin8Error::InsufficientBalance
+    );
+    
+    let player_key = ctx.accounts.player.key();
+    if ctx.accounts.signer.key() != player_key {
+        let session_token = ctx.accounts.session_token
+            .as_mut()
+            .ok_or(Domin8Error::Unauthorized)?;
+        require!(
+            !session_token.is_expired(Clock::get()?.unix_timestamp),
+            Domin8Error::SessionExpired
+        );
+        require!(
+            amount <= session_token.remaining_limit(),
+            Domin8Error::SessionLimitExceeded
+        );
+        session_token.spent = session_token.spent.saturating_add(amount);
+    }
+    
+    validate_bet(
+        &ctx.accounts.config,
+        &ctx.accounts.room,
+        &ctx.accounts.game_round,
+        ctx.accounts.tournament.as_deref_mut(),
+        &player_key,
+        amount,
+        join_code.as_deref(),
+    )?;
+    
+    // Direct lamport transfer (player balance is program-owned)
+    **ctx.accounts.player_balance.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.vault.try_borrow_mut_lamports()? += amount;
+    ctx.accounts.player_balance.balance -= amount;
+    
+    record_bet(
+        &ctx.accounts.config,
+        &ctx.accounts.room,
+        &mut ctx.accounts.game_round,
+        &mut ctx.accounts.player_stats,
+        player_key,
+        amount,
+        BetSource::Balance,
+    )
+}
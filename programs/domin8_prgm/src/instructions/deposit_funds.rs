@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{GameConfig, PlayerBalance};
+use crate::errors::Domin8Error;
+use crate::constants::{GAME_CONFIG_SEED, PLAYER_BALANCE_SEED};
+
+#[derive(Accounts)]
+pub struct DepositFunds<'info> {
+    #[account(
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    /// Created on the player's first deposit, rent paid by the player
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = PlayerBalance::LEN,
+        seeds = [PLAYER_BALANCE_SEED, player.key().as_ref()],
+        bump
+    )]
+    pub player_balance: Account<'info, PlayerBalance>,
+
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Convert SOL into `coins` Game Coins at the configured rate
+pub fn deposit_funds(ctx: Context<DepositFunds>, coins: u64) -> Result<()> {
+    let lamports = ctx.accounts.config
+        .coins_to_lamports(coins)
+        .ok_or(Domin8Error::InvalidCoinRate)?;
+    require!(lamports > 0, Domin8Error::InvalidBetAmount);
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.player.to_account_info(),
+                to: ctx.accounts.player_balance.to_account_info(),
+            },
+        ),
+        lamports,
+    )?;
+
+    let player_balance = &mut ctx.accounts.player_balance;
+    player_balance.player = ctx.accounts.player.key();
+    player_balance.balance = player_balance.balance
+        .checked_add(lamports)
+        .ok_or(Domin8Error::ArithmeticOverflow)?;
+    player_balance.total_deposited = player_balance.total_deposited.saturating_add(lamports);
+
+    msg!("Deposited {} coins ({} lamports) for {}", coins, lamports, player_balance.player);
+
+    Ok(())
+}
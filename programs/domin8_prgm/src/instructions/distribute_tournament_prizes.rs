@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameConfig, Tournament, TournamentStatus};
+use crate::errors::Domin8Error;
+use crate::constants::{GAME_CONFIG_SEED, TOURNAMENT_SEED};
+
+#[derive(Accounts)]
+pub struct DistributeTournamentPrizes<'info> {
+    #[account(
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [TOURNAMENT_SEED, tournament.tournament_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = tournament.status == TournamentStatus::Finished @ Domin8Error::TournamentNotFinished
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// The crank authority
+    #[account(
+        constraint = crank.key() == config.authority @ Domin8Error::Unauthorized
+    )]
+    pub crank: Signer<'info>,
+}
+
+/// Pay the prize pool to the top standings once the final round has resolved.
+/// Placed wallets must be passed in remaining_accounts.
+pub fn distribute_tournament_prizes(ctx: Context<DistributeTournamentPrizes>) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    let ranking = tournament.ranking();
+    let payouts = tournament.calculate_prize_payouts(ranking.len());
+
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(tournament.to_account_info().data_len());
+    let available = tournament.to_account_info().lamports().saturating_sub(rent_exempt_minimum);
+    require!(available >= tournament.prize_pool, Domin8Error::InsufficientFunds);
+
+    for (wallet, payout) in ranking.iter().zip(payouts.iter()).filter(|(_, payout)| **payout > 0) {
+        let account = ctx.remaining_accounts
+            .iter()
+            .find(|account| account.key() == *wallet)
+            .ok_or(Domin8Error::InvalidWinnerAccount)?;
+
+        // Direct lamport transfer (tournament is program-owned)
+        **tournament.to_account_info().try_borrow_mut_lamports()? -= *payout;
+        **account.try_borrow_mut_lamports()? += *payout;
+
+        msg!("Tournament {} prize: {} to {}", tournament.tournament_id, payout, wallet);
+    }
+
+    tournament.status = TournamentStatus::Paid;
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameConfig, Season};
+use crate::errors::Domin8Error;
+use crate::constants::{GAME_CONFIG_SEED, SEASON_SEED};
+
+#[derive(Accounts)]
+pub struct FinalizeSeason<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEASON_SEED, season.season_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = !season.finalized @ Domin8Error::SeasonAlreadyFinalized
+    )]
+    pub season: Account<'info, Season>,
+
+    /// Receives the prize pool when nobody scored during the season
+    #[account(
+        mut,
+        constraint = treasury.key() == config.treasury @ Domin8Error::InvalidTreasury
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    /// The crank authority
+    #[account(
+        constraint = crank.key() == config.authority @ Domin8Error::Unauthorized
+    )]
+    pub crank: Signer<'info>,
+}
+
+/// Pay the season prize pool to the top of the leaderboard after the season ends,
+/// or to the treasury if the leaderboard is empty.
+/// Placed wallets must be passed in remaining_accounts.
+pub fn finalize_season(ctx: Context<FinalizeSeason>) -> Result<()> {
+    let season = &mut ctx.accounts.season;
+    require!(Clock::get()?.unix_timestamp > season.end_ts, Domin8Error::SeasonNotEnded);
+
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(season.to_account_info().data_len());
+    season.prize_pool = season.to_account_info().lamports().saturating_sub(rent_exempt_minimum);
+
+    let ranking: Vec<Pubkey> = season.leaderboard.iter().map(|entry| entry.wallet).collect();
+    if ranking.is_empty() && season.prize_pool > 0 {
+        let prize_pool = season.prize_pool;
+        **season.to_account_info().try_borrow_mut_lamports()? -= prize_pool;
+        **ctx.accounts.treasury.try_borrow_mut_lamports()? += prize_pool;
+
+        msg!("Season {} had no scores, {} returned to the treasury", season.season_id, prize_pool);
+    }
+
+    let payouts = season.calculate_prize_payouts(ranking.len());
+
+    for (wallet, payout) in ranking.iter().zip(payouts.iter()).filter(|(_, payout)| **payout > 0) {
+        let account = ctx.remaining_accounts
+            .iter()
+            .find(|account| account.key() == *wallet)
+            .ok_or(Domin8Error::InvalidWinnerAccount)?;
+
+        // Direct lamport transfer (season is program-owned)
+        **season.to_account_info().try_borrow_mut_lamports()? -= *payout;
+        **account.try_borrow_mut_lamports()? += *payout;
+
+        msg!("Season {} prize: {} to {}", season.season_id, payout, wallet);
+    }
+
+    season.finalized = true;
+    if ctx.accounts.config.current_season == season.key() {
+        ctx.accounts.config.current_season = Pubkey::default();
+    }
+
+    Ok(())
+}
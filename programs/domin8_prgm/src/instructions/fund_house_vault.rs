@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{GameConfig, HouseVault};
+use crate::errors::Domin8Error;
+use crate::constants::{GAME_CONFIG_SEED, HOUSE_VAULT_SEED};
+
+#[derive(Accounts)]
+pub struct FundHouseVault<'info> {
+    #[account(
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [HOUSE_VAULT_SEED],
+        bump
+    )]
+    pub house_vault: Account<'info, HouseVault>,
+
+    #[account(
+        mut,
+        constraint = authority.key() == config.authority @ Domin8Error::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Add SOL to the house bankroll
+pub fn fund_house_vault(ctx: Context<FundHouseVault>, amount: u64) -> Result<()> {
+    require!(amount > 0, Domin8Error::InvalidBetAmount);
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.authority.to_account_info(),
+                to: ctx.accounts.house_vault.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let house_vault = &mut ctx.accounts.house_vault;
+    house_vault.total_funded = house_vault.total_funded
+        .checked_add(amount)
+        .ok_or(Domin8Error::ArithmeticOverflow)?;
+
+    msg!("House vault funded with {} lamports", amount);
+
+    Ok(())
+}
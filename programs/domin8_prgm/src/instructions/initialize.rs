@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::state::{GameConfig, GameRound, GameDurationConfig, GameStatus};
+use crate::state::{GameConfig, GameRound, GameDurationConfig, GameMode, GameStatus, Room, RoomAccess};
 use crate::constants::*;
 
 #[derive(Accounts)]
@@ -13,18 +13,28 @@ pub struct Initialize<'info> {
     )]
     pub config: Account<'info, GameConfig>,
     
+    /// Default room, created with the program-wide defaults
+    #[account(
+        init,
+        payer = authority,
+        space = Room::LEN,
+        seeds = [ROOM_SEED, DEFAULT_ROOM_ID.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub room: Account<'info, Room>,
+    
     #[account(
         init,
         payer = authority,
         space = GameRound::LEN,
-        seeds = [GAME_ROUND_SEED],
+        seeds = [GAME_ROUND_SEED, DEFAULT_ROOM_ID.to_le_bytes().as_ref()],
         bump
     )]
     pub game_round: Account<'info, GameRound>,
     
-    /// CHECK: This is the vault PDA that will hold game funds
+    /// CHECK: This is the vault PDA that will hold the default room's funds
     #[account(
-        seeds = [VAULT_SEED],
+        seeds = [VAULT_SEED, DEFAULT_ROOM_ID.to_le_bytes().as_ref()],
         bump
     )]
     pub vault: UncheckedAccount<'info>,
@@ -40,21 +50,72 @@ pub fn initialize(
     treasury: Pubkey,
 ) -> Result<()> {
     let config = &mut ctx.accounts.config;
+    let room = &mut ctx.accounts.room;
     let game_round = &mut ctx.accounts.game_round;
     
     // Initialize game configuration
     config.authority = ctx.accounts.authority.key();
     config.treasury = treasury;
-    config.house_fee_basis_points = HOUSE_FEE_BASIS_POINTS;
-    config.min_bet_lamports = MIN_BET_LAMPORTS;
+    
+    // Solo-vs-house mode is opt-in once the house vault is funded
+    config.solo_mode_enabled = false;
+    config.solo_win_probability_bps = DEFAULT_SOLO_WIN_PROBABILITY_BPS;
+    config.solo_house_match_bps = DEFAULT_SOLO_HOUSE_MATCH_BPS;
+    config.house_max_exposure_bps = DEFAULT_HOUSE_MAX_EXPOSURE_BPS;
+    
+    // LP pool earns nothing until the authority opts in
+    config.lp_fee_share_bps = DEFAULT_LP_FEE_SHARE_BPS;
+    config.lp_withdrawal_cooldown = DEFAULT_LP_WITHDRAWAL_COOLDOWN;
+    
+    config.creator_fee_share_bps = DEFAULT_CREATOR_FEE_SHARE_BPS;
+    config.champion_base_uri = String::new();
+    config.champion_character_count = 0;
+    config.max_nft_bonus_bps = DEFAULT_MAX_NFT_BONUS_BPS;
+    config.current_season = Pubkey::default();
+    config.referral_fee_share_bps = DEFAULT_REFERRAL_FEE_SHARE_BPS;
+    config.coins_per_sol = DEFAULT_COINS_PER_SOL;
+    
+    // No relayer until the authority configures one
+    config.relayer = Pubkey::default();
+    config.relayer_fee_per_bet = 0;
+    config.max_relayer_fee_bps = 0;
+    
+    config.bet_withdrawal_penalty_bps = DEFAULT_BET_WITHDRAWAL_PENALTY_BPS;
+    config.min_players = DEFAULT_MIN_PLAYERS;
+    config.max_window_extensions = DEFAULT_MAX_WINDOW_EXTENSIONS;
+    config.late_bet_window = DEFAULT_LATE_BET_WINDOW;
+    config.late_bet_extension = 0;
+    config.max_late_bet_extension = 0;
+    
+    // Wallet wager cap is off until configured
+    config.max_wallet_pot_share_bps = 0;
+    config.wallet_cap_min_pot = 0;
+    config.fee_tiers = Vec::new();
+    config.fee_split = Vec::new();
+    
+    // Default room: native SOL, winner takes all
+    room.room_id = DEFAULT_ROOM_ID;
+    room.min_bet = MIN_BET_LAMPORTS;
+    room.max_bet = DEFAULT_MAX_BET;
+    room.house_fee_basis_points = HOUSE_FEE_BASIS_POINTS;
+    room.game_mode = GameMode::WinnerTakesAll;
+    room.prize_split_basis_points = [0u16; MAX_PRIZE_PLACES];
+    room.prize_split_basis_points[0] = BASIS_POINTS_DENOMINATOR;
+    room.bet_mint = Pubkey::default();
+    room.bet_mint_decimals = 9;
+    room.creator = ctx.accounts.authority.key();
+    room.access = RoomAccess::Public;
+    room.join_code_hash = [0u8; 32];
+    room.allowlist = Vec::new();
     
     // Set default durations for small games
-    config.small_game_duration_config = GameDurationConfig {
+    room.duration_config = GameDurationConfig {
         waiting_phase_duration: DEFAULT_SMALL_GAME_WAITING_DURATION,
     };
     
     
     // Initialize game round in idle state
+    game_round.room_id = DEFAULT_ROOM_ID;
     game_round.round_id = 0;
     game_round.status = GameStatus::Idle;
     game_round.start_timestamp = 0;
@@ -62,6 +123,8 @@ pub fn initialize(
     // game_round.finalists = Vec::new(); // Removed for small games MVP
     // game_round.spectator_bets = Vec::new(); // Removed for small games MVP
     game_round.initial_pot = 0;
+    game_round.house_stake = 0;
+    game_round.solo_win_probability_bps = 0;
     // game_round.spectator_pot = 0; // Removed for small games MVP
     game_round.winner = Pubkey::default();
     // game_round.finalist_randomness_account = Pubkey::default(); // Removed for small games MVP
@@ -70,6 +133,12 @@ pub fn initialize(
     game_round.vrf_request_pubkey = Pubkey::default();
     game_round.vrf_seed = [0u8; 32];
     game_round.randomness_fulfilled = false;
+    game_round.tournament = Pubkey::default();
+    game_round.sponsored_bets = 0;
+    game_round.window_extensions = 0;
+    game_round.close_at = 0;
+    game_round.late_bet_extension = 0;
+    game_round.accrued_fees = 0;
     
     msg!("Domin8 game initialized with authority: {}", ctx.accounts.authority.key());
     
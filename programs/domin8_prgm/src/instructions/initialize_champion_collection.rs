@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::metadata::{
+    create_master_edition_v3, create_metadata_accounts_v3,
+    mpl_token_metadata::types::{CollectionDetails, Creator, DataV2},
+    CreateMasterEditionV3, CreateMetadataAccountsV3, Metadata,
+};
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+use crate::state::GameConfig;
+use crate::errors::Domin8Error;
+use crate::constants::*;
+
+#[derive(Accounts)]
+pub struct InitializeChampionCollection<'info> {
+    #[account(
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    /// CHECK: PDA that is mint and update authority of the collection and every champion NFT
+    #[account(
+        seeds = [NFT_AUTHORITY_SEED],
+        bump
+    )]
+    pub nft_authority: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [CHAMPION_COLLECTION_SEED],
+        bump,
+        mint::decimals = 0,
+        mint::authority = nft_authority,
+        mint::freeze_authority = nft_authority
+    )]
+    pub collection_mint: Box<Account<'info, Mint>>,
+
+    /// Holds the single collection NFT
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = collection_mint,
+        associated_token::authority = nft_authority
+    )]
+    pub collection_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Collection metadata PDA, derived and validated by Token Metadata
+    #[account(mut)]
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Collection master edition PDA, derived and validated by Token Metadata
+    #[account(mut)]
+    pub collection_master_edition: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = authority.key() == config.authority @ Domin8Error::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Create the "Royal Rumble Champions" collection NFT
+pub fn initialize_champion_collection(ctx: Context<InitializeChampionCollection>, uri: String) -> Result<()> {
+    require!(uri.len() <= MAX_NFT_URI_LENGTH, Domin8Error::InvalidNftUri);
+
+    let nft_authority_bump = [ctx.bumps.nft_authority];
+    let signer_seeds: &[&[&[u8]]] = &[&[NFT_AUTHORITY_SEED, &nft_authority_bump]];
+
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.collection_mint.to_account_info(),
+                to: ctx.accounts.collection_token_account.to_account_info(),
+                authority: ctx.accounts.nft_authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+
+    create_metadata_accounts_v3(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            CreateMetadataAccountsV3 {
+                metadata: ctx.accounts.collection_metadata.to_account_info(),
+                mint: ctx.accounts.collection_mint.to_account_info(),
+                mint_authority: ctx.accounts.nft_authority.to_account_info(),
+                payer: ctx.accounts.authority.to_account_info(),
+                update_authority: ctx.accounts.nft_authority.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        DataV2 {
+            name: CHAMPION_COLLECTION_NAME.to_string(),
+            symbol: CHAMPION_NFT_SYMBOL.to_string(),
+            uri,
+            seller_fee_basis_points: CHAMPION_ROYALTY_BASIS_POINTS,
+            creators: Some(vec![Creator {
+                address: ctx.accounts.config.treasury,
+                verified: false,
+                share: 100,
+            }]),
+            collection: None,
+            uses: None,
+        },
+        true,
+        true,
+        Some(CollectionDetails::V1 { size: 0 }),
+    )?;
+
+    create_master_edition_v3(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            CreateMasterEditionV3 {
+                edition: ctx.accounts.collection_master_edition.to_account_info(),
+                mint: ctx.accounts.collection_mint.to_account_info(),
+                update_authority: ctx.accounts.nft_authority.to_account_info(),
+                mint_authority: ctx.accounts.nft_authority.to_account_info(),
+                payer: ctx.accounts.authority.to_account_info(),
+                metadata: ctx.accounts.collection_metadata.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        Some(0),
+    )?;
+
+    msg!("Champion collection created: {}", ctx.accounts.collection_mint.key());
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameConfig, HouseVault};
+use crate::errors::Domin8Error;
+use crate::constants::{GAME_CONFIG_SEED, HOUSE_VAULT_SEED};
+
+#[derive(Accounts)]
+pub struct InitializeHouseVault<'info> {
+    #[account(
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = HouseVault::LEN,
+        seeds = [HOUSE_VAULT_SEED],
+        bump
+    )]
+    pub house_vault: Account<'info, HouseVault>,
+
+    #[account(
+        mut,
+        constraint = authority.key() == config.authority @ Domin8Error::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the house bankroll PDA used to match solo players
+pub fn initialize_house_vault(ctx: Context<InitializeHouseVault>) -> Result<()> {
+    let house_vault = &mut ctx.accounts.house_vault;
+    house_vault.total_funded = 0;
+    house_vault.total_withdrawn = 0;
+    house_vault.total_matched = 0;
+    house_vault.total_won = 0;
+    house_vault.reserved_stake = 0;
+    house_vault.total_shares = 0;
+    house_vault.pending_withdrawals = 0;
+    house_vault.total_lp_fees = 0;
+
+    msg!("House vault initialized: {}", house_vault.key());
+
+    Ok(())
+}
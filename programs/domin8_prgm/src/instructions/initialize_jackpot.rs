@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameConfig, Jackpot};
+use crate::errors::Domin8Error;
+use crate::constants::{GAME_CONFIG_SEED, JACKPOT_SEED, MAX_JACKPOT_CONTRIBUTION_BPS};
+
+#[derive(Accounts)]
+pub struct InitializeJackpot<'info> {
+    #[account(
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Jackpot::LEN,
+        seeds = [JACKPOT_SEED],
+        bump
+    )]
+    pub jackpot: Account<'info, Jackpot>,
+
+    #[account(
+        mut,
+        constraint = authority.key() == config.authority @ Domin8Error::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the progressive jackpot PDA
+pub fn initialize_jackpot(
+    ctx: Context<InitializeJackpot>,
+    contribution_bps: u16,
+    odds: u64,
+) -> Result<()> {
+    require!(
+        contribution_bps <= MAX_JACKPOT_CONTRIBUTION_BPS && odds > 0,
+        Domin8Error::InvalidJackpotConfig
+    );
+
+    let jackpot = &mut ctx.accounts.jackpot;
+    jackpot.balance = 0;
+    jackpot.contribution_bps = contribution_bps;
+    jackpot.odds = odds;
+    jackpot.total_contributed = 0;
+    jackpot.total_paid = 0;
+    jackpot.last_winner = Pubkey::default();
+    jackpot.last_payout = 0;
+    jackpot.last_won_round = 0;
+
+    msg!("Jackpot initialized: {} bps per pot, 1 in {} odds", contribution_bps, odds);
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+use crate::state::{GameConfig, HouseVault};
+use crate::errors::Domin8Error;
+use crate::constants::{GAME_CONFIG_SEED, HOUSE_VAULT_SEED, LP_MINT_SEED, LP_SHARE_DECIMALS};
+
+#[derive(Accounts)]
+pub struct InitializeLpMint<'info> {
+    #[account(
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        seeds = [HOUSE_VAULT_SEED],
+        bump
+    )]
+    pub house_vault: Account<'info, HouseVault>,
+
+    /// LP share mint, minted and burned only by the house vault PDA
+    #[account(
+        init,
+        payer = authority,
+        seeds = [LP_MINT_SEED],
+        bump,
+        mint::decimals = LP_SHARE_DECIMALS,
+        mint::authority = house_vault
+    )]
+    pub lp_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = authority.key() == config.authority @ Domin8Error::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the SPL mint for house pool LP shares
+pub fn initialize_lp_mint(ctx: Context<InitializeLpMint>) -> Result<()> {
+    msg!("LP share mint initialized: {}", ctx.accounts.lp_mint.key());
+
+    Ok(())
+}
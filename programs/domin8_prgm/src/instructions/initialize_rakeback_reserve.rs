@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameConfig, RakebackReserve};
+use crate::errors::Domin8Error;
+use crate::constants::{GAME_CONFIG_SEED, MAX_RAKEBACK_BPS, RAKEBACK_RESERVE_SEED};
+
+#[derive(Accounts)]
+pub struct InitializeRakebackReserve<'info> {
+    #[account(
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = RakebackReserve::LEN,
+        seeds = [RAKEBACK_RESERVE_SEED],
+        bump
+    )]
+    pub rakeback_reserve: Account<'info, RakebackReserve>,
+
+    #[account(
+        mut,
+        constraint = authority.key() == config.authority @ Domin8Error::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the rakeback reserve PDA
+pub fn initialize_rakeback_reserve(
+    ctx: Context<InitializeRakebackReserve>,
+    rakeback_bps: u16,
+) -> Result<()> {
+    require!(
+        rakeback_bps <= MAX_RAKEBACK_BPS,
+        Domin8Error::InvalidRakebackConfig
+    );
+
+    let rakeback_reserve = &mut ctx.accounts.rakeback_reserve;
+    rakeback_reserve.rakeback_bps = rakeback_bps;
+    rakeback_reserve.total_funded = 0;
+    rakeback_reserve.total_claimed = 0;
+
+    msg!("Rakeback reserve initialized: {} bps of the house fee", rakeback_bps);
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{GameConfig, Room};
+use crate::errors::Domin8Error;
+use crate::constants::{GAME_CONFIG_SEED, ROOM_SEED, VAULT_SEED, VAULT_TOKEN_SEED};
+
+#[derive(Accounts)]
+pub struct InitializeTokenVault<'info> {
+    #[account(
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        seeds = [ROOM_SEED, room.room_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub room: Account<'info, Room>,
+
+    /// CHECK: This is the room's vault PDA that owns its token accounts
+    #[account(
+        seeds = [VAULT_SEED, room.room_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(
+        mint::token_program = token_program
+    )]
+    pub bet_mint: InterfaceAccount<'info, Mint>,
+
+    /// Token account holding bets for this mint, owned by the vault PDA
+    #[account(
+        init,
+        payer = authority,
+        seeds = [VAULT_TOKEN_SEED, room.room_id.to_le_bytes().as_ref(), bet_mint.key().as_ref()],
+        bump,
+        token::mint = bet_mint,
+        token::authority = vault,
+        token::token_program = token_program
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = authority.key() == config.authority @ Domin8Error::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Create a room's vault token account for an SPL token or Token-2022 bet mint
+pub fn initialize_token_vault(ctx: Context<InitializeTokenVault>) -> Result<()> {
+    msg!("Room {} token vault {} created for mint {}",
+         ctx.accounts.room.room_id, ctx.accounts.vault_token_account.key(), ctx.accounts.bet_mint.key());
+
+    Ok(())
+}
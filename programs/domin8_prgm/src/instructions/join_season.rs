@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::{PlayerStats, Season, SeasonStats};
+use crate::errors::Domin8Error;
+use crate::constants::{PLAYER_STATS_SEED, SEASON_SEED, SEASON_STATS_SEED};
+
+#[derive(Accounts)]
+pub struct JoinSeason<'info> {
+    #[account(
+        seeds = [SEASON_SEED, season.season_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub season: Account<'info, Season>,
+
+    #[account(
+        init,
+        payer = player,
+        space = SeasonStats::LEN,
+        seeds = [SEASON_STATS_SEED, season.key().as_ref(), player.key().as_ref()],
+        bump
+    )]
+    pub season_stats: Account<'info, SeasonStats>,
+
+    /// CHECK: The player's stats PDA, uninitialized if the wallet has never placed a bet
+    #[account(
+        seeds = [PLAYER_STATS_SEED, player.key().as_ref()],
+        bump
+    )]
+    pub player_stats: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Enter a season; only activity after joining earns season points
+pub fn join_season(ctx: Context<JoinSeason>) -> Result<()> {
+    let season = &ctx.accounts.season;
+    require!(
+        !season.finalized && Clock::get()?.unix_timestamp <= season.end_ts,
+        Domin8Error::SeasonNotActive
+    );
+
+    let player_stats = PlayerStats::load_if_initialized(&ctx.accounts.player_stats)?;
+
+    let season_stats = &mut ctx.accounts.season_stats;
+    season_stats.season = season.key();
+    season_stats.player = ctx.accounts.player.key();
+    season_stats.set_baseline(player_stats.as_ref());
+    season_stats.champion_nfts = 0;
+    season_stats.points = 0;
+
+    msg!("{} joined season {}", season_stats.player, season.season_id);
+
+    Ok(())
+}
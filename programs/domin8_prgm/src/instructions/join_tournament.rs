@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{Tournament, TournamentStanding, TournamentStatus};
+use crate::errors::Domin8Error;
+use crate::constants::{MAX_TOURNAMENT_ENTRANTS, TOURNAMENT_SEED};
+
+#[derive(Accounts)]
+pub struct JoinTournament<'info> {
+    #[account(
+        mut,
+        seeds = [TOURNAMENT_SEED, tournament.tournament_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = tournament.status == TournamentStatus::Registration @ Domin8Error::TournamentRegistrationClosed
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Pay the buy-in into the prize pool and join the standings
+pub fn join_tournament(ctx: Context<JoinTournament>) -> Result<()> {
+    let player_key = ctx.accounts.player.key();
+    let tournament = &ctx.accounts.tournament;
+
+    require!(!tournament.is_entrant(&player_key), Domin8Error::AlreadyRegistered);
+    require!(
+        tournament.standings.len() < MAX_TOURNAMENT_ENTRANTS,
+        Domin8Error::TournamentFull
+    );
+
+    let buy_in = tournament.buy_in;
+    if buy_in > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.player.to_account_info(),
+                    to: ctx.accounts.tournament.to_account_info(),
+                },
+            ),
+            buy_in,
+        )?;
+    }
+
+    let tournament = &mut ctx.accounts.tournament;
+    tournament.prize_pool = tournament.prize_pool
+        .checked_add(buy_in)
+        .ok_or(Domin8Error::ArithmeticOverflow)?;
+    tournament.standings.push(TournamentStanding {
+        wallet: player_key,
+        points: 0,
+        rounds_won: 0,
+    });
+
+    msg!("{} joined tournament {} ({} entrants, prize pool {})",
+         player_key, tournament.tournament_id, tournament.standings.len(), tournament.prize_pool);
+
+    Ok(())
+}
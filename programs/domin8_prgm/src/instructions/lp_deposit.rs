@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+use crate::state::HouseVault;
+use crate::errors::Domin8Error;
+use crate::constants::{HOUSE_VAULT_SEED, LP_MINT_SEED};
+
+#[derive(Accounts)]
+pub struct LpDeposit<'info> {
+    #[account(
+        mut,
+        seeds = [HOUSE_VAULT_SEED],
+        bump
+    )]
+    pub house_vault: Account<'info, HouseVault>,
+
+    #[account(
+        mut,
+        seeds = [LP_MINT_SEED],
+        bump
+    )]
+    pub lp_mint: Account<'info, Mint>,
+
+    /// Depositor's token account receiving LP shares
+    #[account(
+        mut,
+        token::mint = lp_mint,
+        token::authority = depositor
+    )]
+    pub depositor_share_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Deposit SOL into the house bankroll in exchange for LP shares
+pub fn lp_deposit(ctx: Context<LpDeposit>, amount: u64) -> Result<()> {
+    require!(amount > 0, Domin8Error::InvalidBetAmount);
+
+    // Price shares against the bankroll before this deposit lands. The authority has to
+    // withdraw its own capital first, since LP shares would otherwise claim all of it.
+    let house_vault = &ctx.accounts.house_vault;
+    let bankroll = house_vault.share_bankroll(&house_vault.to_account_info())?;
+    require!(!house_vault.has_unshared_capital(bankroll), Domin8Error::HouseCapitalOutstanding);
+    let shares = house_vault
+        .shares_for_deposit(amount, bankroll)
+        .ok_or(Domin8Error::InvalidShareAmount)?;
+    require!(shares > 0, Domin8Error::InvalidShareAmount);
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.depositor.to_account_info(),
+                to: ctx.accounts.house_vault.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let house_vault_bump = [ctx.bumps.house_vault];
+    let signer_seeds: &[&[&[u8]]] = &[&[HOUSE_VAULT_SEED, &house_vault_bump]];
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.lp_mint.to_account_info(),
+                to: ctx.accounts.depositor_share_account.to_account_info(),
+                authority: ctx.accounts.house_vault.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        shares,
+    )?;
+
+    let house_vault = &mut ctx.accounts.house_vault;
+    house_vault.total_shares = house_vault.total_shares
+        .checked_add(shares)
+        .ok_or(Domin8Error::ArithmeticOverflow)?;
+
+    msg!("LP deposit: {} lamports for {} shares", amount, shares);
+
+    Ok(())
+}
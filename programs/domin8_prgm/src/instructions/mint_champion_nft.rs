@@ -0,0 +1,242 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::metadata::{
+    create_master_edition_v3, create_metadata_accounts_v3, verify_sized_collection_item,
+    mpl_token_metadata::types::{Collection, Creator, DataV2},
+    CreateMasterEditionV3, CreateMetadataAccountsV3, Metadata, VerifySizedCollectionItem,
+};
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+use crate::state::{ChampionNft, GameConfig, RoundResult};
+use crate::errors::Domin8Error;
+use crate::constants::*;
+use crate::replay::champion_character;
+
+#[derive(Accounts)]
+pub struct MintChampionNft<'info> {
+    #[account(
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Box<Account<'info, GameConfig>>,
+
+    /// Treasury account receiving the mint fee
+    #[account(
+        mut,
+        constraint = treasury.key() == config.treasury @ Domin8Error::InvalidTreasury
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    /// The won round; its champion mint can only be created once
+    #[account(
+        seeds = [
+            ROUND_RESULT_SEED,
+            round_result.room_id.to_le_bytes().as_ref(),
+            round_result.round_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        constraint = round_result.winner == winner.key() @ Domin8Error::NotRoundWinner
+    )]
+    pub round_result: Box<Account<'info, RoundResult>>,
+
+    #[account(
+        init,
+        payer = winner,
+        seeds = [CHAMPION_MINT_SEED, round_result.key().as_ref()],
+        bump,
+        mint::decimals = 0,
+        mint::authority = nft_authority,
+        mint::freeze_authority = nft_authority
+    )]
+    pub champion_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = winner,
+        associated_token::mint = champion_mint,
+        associated_token::authority = winner
+    )]
+    pub winner_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = winner,
+        space = ChampionNft::LEN,
+        seeds = [CHAMPION_NFT_SEED, champion_mint.key().as_ref()],
+        bump
+    )]
+    pub champion_nft: Box<Account<'info, ChampionNft>>,
+
+    /// CHECK: Champion metadata PDA, derived and validated by Token Metadata
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Champion master edition PDA, derived and validated by Token Metadata
+    #[account(mut)]
+    pub master_edition: UncheckedAccount<'info>,
+
+    /// CHECK: PDA that is mint and update authority of every champion NFT
+    #[account(
+        seeds = [NFT_AUTHORITY_SEED],
+        bump
+    )]
+    pub nft_authority: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [CHAMPION_COLLECTION_SEED],
+        bump
+    )]
+    pub collection_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Collection metadata PDA, validated by Token Metadata
+    #[account(mut)]
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Collection master edition PDA, validated by Token Metadata
+    pub collection_master_edition: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub winner: Signer<'info>,
+
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Mint the winner's "Royal Rumble Champions" NFT for a resolved round.
+/// The metadata URI and champion character are derived from the round, so
+/// every field of the NFT can be checked against its `RoundResult`.
+pub fn mint_champion_nft(ctx: Context<MintChampionNft>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    require!(
+        !config.champion_base_uri.is_empty() && config.champion_character_count > 0,
+        Domin8Error::ChampionNftNotConfigured
+    );
+    let round_result = &ctx.accounts.round_result;
+    let uri = config.champion_nft_uri(round_result.room_id, round_result.round_id);
+    let character_id = champion_character(&round_result.replay_seed, config.champion_character_count);
+
+    // Mint fee to the treasury
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.winner.to_account_info(),
+                to: ctx.accounts.treasury.to_account_info(),
+            },
+        ),
+        CHAMPION_NFT_MINT_FEE,
+    )?;
+
+    let nft_authority_bump = [ctx.bumps.nft_authority];
+    let signer_seeds: &[&[&[u8]]] = &[&[NFT_AUTHORITY_SEED, &nft_authority_bump]];
+
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.champion_mint.to_account_info(),
+                to: ctx.accounts.winner_token_account.to_account_info(),
+                authority: ctx.accounts.nft_authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+
+    create_metadata_accounts_v3(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            CreateMetadataAccountsV3 {
+                metadata: ctx.accounts.metadata.to_account_info(),
+                mint: ctx.accounts.champion_mint.to_account_info(),
+                mint_authority: ctx.accounts.nft_authority.to_account_info(),
+                payer: ctx.accounts.winner.to_account_info(),
+                update_authority: ctx.accounts.nft_authority.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        DataV2 {
+            name: CHAMPION_NFT_NAME.to_string(),
+            symbol: CHAMPION_NFT_SYMBOL.to_string(),
+            uri,
+            seller_fee_basis_points: CHAMPION_ROYALTY_BASIS_POINTS,
+            creators: Some(vec![Creator {
+                address: ctx.accounts.config.treasury,
+                verified: false,
+                share: 100,
+            }]),
+            collection: Some(Collection {
+                verified: false,
+                key: ctx.accounts.collection_mint.key(),
+            }),
+            uses: None,
+        },
+        true,
+        true,
+        None,
+    )?;
+
+    create_master_edition_v3(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            CreateMasterEditionV3 {
+                edition: ctx.accounts.master_edition.to_account_info(),
+                mint: ctx.accounts.champion_mint.to_account_info(),
+                update_authority: ctx.accounts.nft_authority.to_account_info(),
+                mint_authority: ctx.accounts.nft_authority.to_account_info(),
+                payer: ctx.accounts.winner.to_account_info(),
+                metadata: ctx.accounts.metadata.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        Some(0),
+    )?;
+
+    verify_sized_collection_item(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            VerifySizedCollectionItem {
+                payer: ctx.accounts.winner.to_account_info(),
+                metadata: ctx.accounts.metadata.to_account_info(),
+                collection_authority: ctx.accounts.nft_authority.to_account_info(),
+                collection_mint: ctx.accounts.collection_mint.to_account_info(),
+                collection_metadata: ctx.accounts.collection_metadata.to_account_info(),
+                collection_master_edition: ctx.accounts.collection_master_edition.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        None,
+    )?;
+
+    // Attributes from the finished round record
+    let champion_nft = &mut ctx.accounts.champion_nft;
+    champion_nft.mint = ctx.accounts.champion_mint.key();
+    champion_nft.room_id = round_result.room_id;
+    champion_nft.round_id = round_result.round_id;
+    champion_nft.winner = round_result.winner;
+    champion_nft.character_id = character_id;
+    champion_nft.bet_mint = round_result.bet_mint;
+    champion_nft.player_count = round_result.players.len() as u8;
+    champion_nft.winner_bet = round_result.players
+        .iter()
+        .find(|p| p.wallet == round_result.winner)
+        .map_or(0, |p| p.total_bet);
+    champion_nft.total_pot = round_result.total_pot;
+    champion_nft.winnings = round_result.winner_payout.saturating_add(round_result.jackpot_payout);
+    champion_nft.won_at = round_result.resolved_at;
+    champion_nft.minted_at = Clock::get()?.unix_timestamp;
+    champion_nft.counted_season = Pubkey::default();
+
+    msg!("Champion NFT {} minted for room {} round {}",
+         champion_nft.mint, champion_nft.room_id, champion_nft.round_id);
+
+    Ok(())
+}
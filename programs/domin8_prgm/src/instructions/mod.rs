@@ -1,6 +1,7 @@
 // Instructions module - contains all instruction handlers
 pub mod initialize;
 pub mod deposit_bet;
+pub mod withdraw_bet;
 // pub mod place_spectator_bet;  // Removed for small games MVP
 // pub mod progress_to_resolution;  // Removed - replaced by unified
 // pub mod progress_to_final_battle;  // Removed for small games MVP
@@ -12,9 +13,90 @@ pub mod deposit_bet;
 // ORAO VRF unified instructions
 pub mod unified_progress_to_resolution;
 pub mod unified_resolve_and_distribute;
+pub mod close_round_result;
+
+// Room instructions
+pub mod create_room;
+pub mod update_room;
+pub mod create_private_room;
+pub mod set_room_access;
+
+// Admin configuration instructions
+pub mod set_prize_split;
+pub mod set_solo_mode;
+pub mod set_lp_pool_config;
+pub mod set_jackpot_config;
+pub mod set_bet_mint;
+pub mod set_creator_fee_share;
+pub mod set_nft_bonus_config;
+pub mod set_champion_nft_config;
+pub mod set_referral_fee_share;
+pub mod set_rakeback_config;
+pub mod set_coin_rate;
+pub mod set_relayer_config;
+pub mod set_bet_withdrawal_penalty;
+pub mod set_min_players;
+pub mod set_late_bet_config;
+pub mod set_wallet_cap;
+pub mod set_fee_tiers;
+pub mod set_fee_split;
+
+// Fee ledger instructions
+pub mod sweep_fees;
+
+// House bankroll instructions
+pub mod initialize_house_vault;
+pub mod fund_house_vault;
+pub mod withdraw_house_vault;
+
+// Liquidity-provider pool instructions
+pub mod initialize_lp_mint;
+pub mod lp_deposit;
+pub mod request_lp_withdrawal;
+pub mod complete_lp_withdrawal;
+
+// Tournament instructions
+pub mod create_tournament;
+pub mod join_tournament;
+pub mod distribute_tournament_prizes;
+pub mod cancel_tournament;
+
+// Champion NFT instructions
+pub mod initialize_champion_collection;
+pub mod mint_champion_nft;
+
+// Season instructions
+pub mod create_season;
+pub mod join_season;
+pub mod update_season_score;
+pub mod finalize_season;
+
+// Referral instructions
+pub mod register_referrer;
+pub mod claim_referral_earnings;
+
+// Game Coins balance instructions
+pub mod deposit_funds;
+pub mod withdraw_funds;
+pub mod deposit_bet_from_balance;
+
+// Session key instructions
+pub mod create_session;
+pub mod revoke_session;
+
+// Rakeback instructions
+pub mod initialize_rakeback_reserve;
+pub mod claim_rakeback;
+
+// Progressive jackpot instructions
+pub mod initialize_jackpot;
+
+// SPL token pool instructions
+pub mod initialize_token_vault;
 
 pub use initialize::*;
 pub use deposit_bet::*;
+pub use withdraw_bet::*;
 // pub use place_spectator_bet::*;  // Removed for small games MVP
 // pub use progress_to_resolution::*;  // Removed - replaced by unified
 // pub use progress_to_final_battle::*;  // Removed for small games MVP
@@ -25,4 +107,84 @@ pub use deposit_bet::*;
 
 // ORAO VRF unified exports
 pub use unified_progress_to_resolution::*;
-pub use unified_resolve_and_distribute::*;
\ No newline at end of file
+pub use unified_resolve_and_distribute::*;
+pub use close_round_result::*;
+
+// Room exports
+pub use create_room::*;
+pub use update_room::*;
+pub use create_private_room::*;
+pub use set_room_access::*;
+
+// Admin configuration exports
+pub use set_prize_split::*;
+pub use set_solo_mode::*;
+pub use set_lp_pool_config::*;
+pub use set_jackpot_config::*;
+pub use set_bet_mint::*;
+pub use set_creator_fee_share::*;
+pub use set_nft_bonus_config::*;
+pub use set_champion_nft_config::*;
+pub use set_referral_fee_share::*;
+pub use set_rakeback_config::*;
+pub use set_coin_rate::*;
+pub use set_relayer_config::*;
+pub use set_bet_withdrawal_penalty::*;
+pub use set_min_players::*;
+pub use set_late_bet_config::*;
+pub use set_wallet_cap::*;
+pub use set_fee_tiers::*;
+pub use set_fee_split::*;
+
+// Fee ledger exports
+pub use sweep_fees::*;
+
+// House bankroll exports
+pub use initialize_house_vault::*;
+pub use fund_house_vault::*;
+pub use withdraw_house_vault::*;
+
+// Liquidity-provider pool exports
+pub use initialize_lp_mint::*;
+pub use lp_deposit::*;
+pub use request_lp_withdrawal::*;
+pub use complete_lp_withdrawal::*;
+
+// Tournament exports
+pub use create_tournament::*;
+pub use join_tournament::*;
+pub use distribute_tournament_prizes::*;
+pub use cancel_tournament::*;
+
+// Champion NFT exports
+pub use initialize_champion_collection::*;
+pub use mint_champion_nft::*;
+
+// Season exports
+pub use create_season::*;
+pub use join_season::*;
+pub use update_season_score::*;
+pub use finalize_season::*;
+
+// Referral exports
+pub use register_referrer::*;
+pub use claim_referral_earnings::*;
+
+// Game Coins balance exports
+pub use deposit_funds::*;
+pub use withdraw_funds::*;
+pub use deposit_bet_from_balance::*;
+
+// Session key exports
+pub use create_session::*;
+pub use revoke_session::*;
+
+// Rakeback exports
+pub use initialize_rakeback_reserve::*;
+pub use claim_rakeback::*;
+
+// Progressive jackpot exports
+pub use initialize_jackpot::*;
+
+// SPL token pool exports
+pub use initialize_token_vault::*;
\ No newline at end of file
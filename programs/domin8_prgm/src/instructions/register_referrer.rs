@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::state::{PlayerStats, Referral, ReferrerAccount};
+use crate::errors::Domin8Error;
+use crate::constants::{PLAYER_STATS_SEED, REFERRAL_SEED, REFERRER_SEED};
+
+#[derive(Accounts)]
+#[instruction(referrer: Pubkey)]
+pub struct RegisterReferrer<'info> {
+    /// Re-registering is allowed until the player's first bet
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = Referral::LEN,
+        seeds = [REFERRAL_SEED, player.key().as_ref()],
+        bump
+    )]
+    pub referral: Account<'info, Referral>,
+
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = ReferrerAccount::LEN,
+        seeds = [REFERRER_SEED, referrer.as_ref()],
+        bump
+    )]
+    pub referrer_account: Account<'info, ReferrerAccount>,
+
+    /// CHECK: The player's stats PDA, uninitialized if the wallet has never placed a bet
+    #[account(
+        seeds = [PLAYER_STATS_SEED, player.key().as_ref()],
+        bump
+    )]
+    pub player_stats: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Register the wallet that referred this player
+pub fn register_referrer(ctx: Context<RegisterReferrer>, referrer: Pubkey) -> Result<()> {
+    let player_key = ctx.accounts.player.key();
+    require!(referrer != player_key, Domin8Error::SelfReferral);
+    let player_stats = PlayerStats::load_if_initialized(&ctx.accounts.player_stats)?;
+    require!(
+        !player_stats.is_some_and(|stats| stats.has_bet()),
+        Domin8Error::ReferralLocked
+    );
+
+    let referral = &mut ctx.accounts.referral;
+    let is_new_registration = referral.referrer != referrer;
+    referral.player = player_key;
+    referral.referrer = referrer;
+    referral.registered_at = Clock::get()?.unix_timestamp;
+
+    let referrer_account = &mut ctx.accounts.referrer_account;
+    referrer_account.referrer = referrer;
+    if is_new_registration {
+        referrer_account.registrations = referrer_account.registrations.saturating_add(1);
+    }
+
+    msg!("{} registered referrer {}", player_key, referrer);
+
+    Ok(())
+}
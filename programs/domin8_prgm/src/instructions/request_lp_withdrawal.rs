@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
+use crate::state::{GameConfig, HouseVault, LpWithdrawal};
+use crate::errors::Domin8Error;
+use crate::constants::{GAME_CONFIG_SEED, HOUSE_VAULT_SEED, LP_MINT_SEED, LP_WITHDRAWAL_SEED};
+
+#[derive(Accounts)]
+pub struct RequestLpWithdrawal<'info> {
+    #[account(
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [HOUSE_VAULT_SEED],
+        bump
+    )]
+    pub house_vault: Account<'info, HouseVault>,
+
+    #[account(
+        mut,
+        seeds = [LP_MINT_SEED],
+        bump
+    )]
+    pub lp_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = lp_mint,
+        token::authority = owner
+    )]
+    pub owner_share_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = LpWithdrawal::LEN,
+        seeds = [LP_WITHDRAWAL_SEED, owner.key().as_ref()],
+        bump
+    )]
+    pub lp_withdrawal: Account<'info, LpWithdrawal>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Burn LP shares and lock their SOL value until the withdrawal cooldown ends
+pub fn request_lp_withdrawal(ctx: Context<RequestLpWithdrawal>, shares: u64) -> Result<()> {
+    require!(shares > 0, Domin8Error::InvalidShareAmount);
+    let clock = Clock::get()?;
+
+    let house_vault = &ctx.accounts.house_vault;
+    let bankroll = house_vault.share_bankroll(&house_vault.to_account_info())?;
+    let lamports = house_vault
+        .lamports_for_shares(shares, bankroll)
+        .ok_or(Domin8Error::InvalidShareAmount)?;
+    // Stakes reserved by open solo rounds must stay in the vault until they settle
+    require!(
+        lamports <= house_vault.available_bankroll(&house_vault.to_account_info())?,
+        Domin8Error::InsufficientBankroll
+    );
+
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.lp_mint.to_account_info(),
+                from: ctx.accounts.owner_share_account.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ),
+        shares,
+    )?;
+
+    let house_vault = &mut ctx.accounts.house_vault;
+    house_vault.total_shares = house_vault.total_shares
+        .checked_sub(shares)
+        .ok_or(Domin8Error::InvalidShareAmount)?;
+    house_vault.pending_withdrawals = house_vault.pending_withdrawals
+        .checked_add(lamports)
+        .ok_or(Domin8Error::ArithmeticOverflow)?;
+
+    let lp_withdrawal = &mut ctx.accounts.lp_withdrawal;
+    lp_withdrawal.owner = ctx.accounts.owner.key();
+    lp_withdrawal.shares = shares;
+    lp_withdrawal.lamports = lamports;
+    lp_withdrawal.requested_at = clock.unix_timestamp;
+    lp_withdrawal.unlock_at = clock.unix_timestamp
+        .checked_add(ctx.accounts.config.lp_withdrawal_cooldown)
+        .ok_or(Domin8Error::ArithmeticOverflow)?;
+
+    msg!("LP withdrawal requested: {} shares for {} lamports, unlocks at {}",
+         shares, lamports, lp_withdrawal.unlock_at);
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+use crate::state::SessionToken;
+use crate::errors::Domin8Error;
+use crate::constants::SESSION_TOKEN_SEED;
+
+#[derive(Accounts)]
+pub struct RevokeSession<'info> {
+    #[account(
+        mut,
+        seeds = [SESSION_TOKEN_SEED, owner.key().as_ref(), session_token.session_key.as_ref()],
+        bump,
+        has_one = owner @ Domin8Error::Unauthorized,
+        close = owner
+    )]
+    pub session_token: Account<'info, SessionToken>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+/// Revoke a session key and reclaim the session token's rent
+pub fn revoke_session(ctx: Context<RevokeSession>) -> Result<()> {
+    msg!("Session {} revoked by {}", ctx.accounts.session_token.session_key, ctx.accounts.owner.key());
+
+    Ok(())
+}
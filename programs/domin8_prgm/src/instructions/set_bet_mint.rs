@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use crate::state::{GameConfig, GameRound, GameStatus, Room};
+use crate::errors::Domin8Error;
+use crate::constants::{GAME_CONFIG_SEED, GAME_ROUND_SEED, MIN_BET_LAMPORTS, ROOM_SEED};
+
+#[derive(Accounts)]
+pub struct SetBetMint<'info> {
+    #[account(
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [ROOM_SEED, room.room_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub room: Account<'info, Room>,
+
+    /// The bet currency can only change between rounds
+    #[account(
+        seeds = [GAME_ROUND_SEED, room.room_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = game_round.status == GameStatus::Idle @ Domin8Error::InvalidGameStatus
+    )]
+    pub game_round: Account<'info, GameRound>,
+
+    /// New bet mint (omit to switch back to native SOL).
+    /// Its vault token account must exist via `initialize_token_vault` before bets are accepted.
+    pub bet_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        constraint = authority.key() == config.authority @ Domin8Error::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Switch a room between native SOL and an SPL token / Token-2022 mint.
+/// `min_bet` is in the new currency's base units (0 = MIN_BET_LAMPORTS when switching to SOL).
+/// The room's maximum bet is cleared since it was denominated in the old currency.
+pub fn set_bet_mint(ctx: Context<SetBetMint>, min_bet: u64) -> Result<()> {
+    let room = &mut ctx.accounts.room;
+
+    match &ctx.accounts.bet_mint {
+        Some(bet_mint) => {
+            require!(min_bet > 0, Domin8Error::InvalidBetAmount);
+            room.bet_mint = bet_mint.key();
+            room.bet_mint_decimals = bet_mint.decimals;
+            room.min_bet = min_bet;
+
+            msg!("Room {} bet mint set to {} ({} decimals), minimum bet {}",
+                 room.room_id, room.bet_mint, room.bet_mint_decimals, min_bet);
+        },
+        None => {
+            room.bet_mint = Pubkey::default();
+            room.bet_mint_decimals = 9;
+            room.min_bet = if min_bet > 0 { min_bet } else { MIN_BET_LAMPORTS };
+
+            msg!("Room {} bet currency set to native SOL, minimum bet {}", room.room_id, room.min_bet);
+        },
+    }
+    room.max_bet = 0;
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::state::GameConfig;
+use crate::errors::Domin8Error;
+use crate::constants::{GAME_CONFIG_SEED, MAX_BET_WITHDRAWAL_PENALTY_BPS};
+
+#[derive(Accounts)]
+pub struct SetBetWithdrawalPenalty<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        constraint = authority.key() == config.authority @ Domin8Error::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Set the penalty kept from stakes withdrawn before the round locks
+pub fn set_bet_withdrawal_penalty(ctx: Context<SetBetWithdrawalPenalty>, penalty_bps: u16) -> Result<()> {
+    require!(penalty_bps <= MAX_BET_WITHDRAWAL_PENALTY_BPS, Domin8Error::InvalidWithdrawalPenalty);
+
+    ctx.accounts.config.bet_withdrawal_penalty_bps = penalty_bps;
+
+    msg!("Bet withdrawal penalty: {} bps", penalty_bps);
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::state::GameConfig;
+use crate::errors::Domin8Error;
+use crate::constants::{GAME_CONFIG_SEED, MAX_CHAMPION_BASE_URI_LENGTH};
+
+#[derive(Accounts)]
+pub struct SetChampionNftConfig<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        constraint = authority.key() == config.authority @ Domin8Error::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Set the base metadata URI and character roster size for champion NFTs
+pub fn set_champion_nft_config(
+    ctx: Context<SetChampionNftConfig>,
+    base_uri: String,
+    character_count: u16,
+) -> Result<()> {
+    require!(base_uri.len() <= MAX_CHAMPION_BASE_URI_LENGTH, Domin8Error::InvalidNftUri);
+
+    let config = &mut ctx.accounts.config;
+    config.champion_base_uri = base_uri;
+    config.champion_character_count = character_count;
+
+    msg!("Champion NFTs use {} with {} characters", config.champion_base_uri, character_count);
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::state::GameConfig;
+use crate::errors::Domin8Error;
+use crate::constants::GAME_CONFIG_SEED;
+
+#[derive(Accounts)]
+pub struct SetCoinRate<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        constraint = authority.key() == config.authority @ Domin8Error::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Set how many Game Coins one SOL buys. Existing balances keep their SOL value.
+pub fn set_coin_rate(ctx: Context<SetCoinRate>, coins_per_sol: u64) -> Result<()> {
+    require!(coins_per_sol > 0, Domin8Error::InvalidCoinRate);
+
+    ctx.accounts.config.coins_per_sol = coins_per_sol;
+
+    msg!("Game Coins rate: {} per SOL", coins_per_sol);
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::state::GameConfig;
+use crate::errors::Domin8Error;
+use crate::constants::{GAME_CONFIG_SEED, BASIS_POINTS_DENOMINATOR};
+
+#[derive(Accounts)]
+pub struct SetCreatorFeeShare<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        constraint = authority.key() == config.authority @ Domin8Error::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Set the share of the treasury's fee paid to private room creators
+pub fn set_creator_fee_share(ctx: Context<SetCreatorFeeShare>, creator_fee_share_bps: u16) -> Result<()> {
+    require!(creator_fee_share_bps <= BASIS_POINTS_DENOMINATOR, Domin8Error::InvalidRoomConfig);
+
+    ctx.accounts.config.creator_fee_share_bps = creator_fee_share_bps;
+
+    msg!("Private room creator fee share: {} bps", creator_fee_share_bps);
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::state::{FeeRecipient, GameConfig};
+use crate::errors::Domin8Error;
+use crate::constants::GAME_CONFIG_SEED;
+
+#[derive(Accounts)]
+pub struct SetFeeSplit<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        constraint = authority.key() == config.authority @ Domin8Error::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Split the treasury's fee between up to 5 recipients (e.g. operations,
+/// buyback and dev fund) with shares summing to 10,000 bps.
+/// An empty split sends the whole fee to `config.treasury` again.
+pub fn set_fee_split(ctx: Context<SetFeeSplit>, fee_split: Vec<FeeRecipient>) -> Result<()> {
+    require!(
+        fee_split.is_empty() || FeeRecipient::is_valid_split(&fee_split),
+        Domin8Error::InvalidFeeSplit
+    );
+
+    msg!("Treasury fee split: {:?}", fee_split);
+    ctx.accounts.config.fee_split = fee_split;
+
+    Ok(())
+}
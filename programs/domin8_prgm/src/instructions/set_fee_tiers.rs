@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::state::{FeeTier, GameConfig};
+use crate::errors::Domin8Error;
+use crate::constants::GAME_CONFIG_SEED;
+
+#[derive(Accounts)]
+pub struct SetFeeTiers<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        constraint = authority.key() == config.authority @ Domin8Error::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Replace the house fee tier table, e.g. 5% below 1 SOL, 4% below 10 SOL and
+/// 3% above as [(1 SOL, 500), (10 SOL, 400), (u64::MAX, 300)].
+/// Tiers only ever lower a room's own fee; an empty table turns them off.
+pub fn set_fee_tiers(ctx: Context<SetFeeTiers>, fee_tiers: Vec<FeeTier>) -> Result<()> {
+    require!(FeeTier::is_valid_table(&fee_tiers), Domin8Error::InvalidFeeTiers);
+
+    msg!("House fee tiers: {:?}", fee_tiers);
+    ctx.accounts.config.fee_tiers = fee_tiers;
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameConfig, Jackpot};
+use crate::errors::Domin8Error;
+use crate::constants::{GAME_CONFIG_SEED, JACKPOT_SEED, MAX_JACKPOT_CONTRIBUTION_BPS};
+
+#[derive(Accounts)]
+pub struct SetJackpotConfig<'info> {
+    #[account(
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [JACKPOT_SEED],
+        bump
+    )]
+    pub jackpot: Account<'info, Jackpot>,
+
+    #[account(
+        constraint = authority.key() == config.authority @ Domin8Error::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Update the jackpot contribution rate and odds
+pub fn set_jackpot_config(
+    ctx: Context<SetJackpotConfig>,
+    contribution_bps: u16,
+    odds: u64,
+) -> Result<()> {
+    require!(
+        contribution_bps <= MAX_JACKPOT_CONTRIBUTION_BPS && odds > 0,
+        Domin8Error::InvalidJackpotConfig
+    );
+
+    let jackpot = &mut ctx.accounts.jackpot;
+    jackpot.contribution_bps = contribution_bps;
+    jackpot.odds = odds;
+
+    msg!("Jackpot config: {} bps per pot, 1 in {} odds", contribution_bps, odds);
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::state::GameConfig;
+use crate::errors::Domin8Error;
+use crate::constants::GAME_CONFIG_SEED;
+
+#[derive(Accounts)]
+pub struct SetLateBetConfig<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        constraint = authority.key() == config.authority @ Domin8Error::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Configure the soft close: bets in the final `late_bet_window` seconds push
+/// the close back by `late_bet_extension` seconds, up to `max_late_bet_extension`
+/// seconds per round. A zero window disables it.
+pub fn set_late_bet_config(
+    ctx: Context<SetLateBetConfig>,
+    late_bet_window: i64,
+    late_bet_extension: i64,
+    max_late_bet_extension: i64,
+) -> Result<()> {
+    require!(
+        late_bet_window >= 0
+            && late_bet_extension >= 0
+            && late_bet_extension <= max_late_bet_extension,
+        Domin8Error::InvalidLateBetConfig
+    );
+
+    let config = &mut ctx.accounts.config;
+    config.late_bet_window = late_bet_window;
+    config.late_bet_extension = late_bet_extension;
+    config.max_late_bet_extension = max_late_bet_extension;
+
+    msg!("Late bets in the final {}s add {}s, up to {}s per round",
+         late_bet_window, late_bet_extension, max_late_bet_extension);
+
+    Ok(())
+}
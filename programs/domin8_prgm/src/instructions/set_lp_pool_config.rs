@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::state::GameConfig;
+use crate::errors::Domin8Error;
+use crate::constants::{GAME_CONFIG_SEED, BASIS_POINTS_DENOMINATOR};
+
+#[derive(Accounts)]
+pub struct SetLpPoolConfig<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        constraint = authority.key() == config.authority @ Domin8Error::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Set the LP share of the house fee and the LP withdrawal cooldown
+pub fn set_lp_pool_config(
+    ctx: Context<SetLpPoolConfig>,
+    fee_share_bps: u16,
+    withdrawal_cooldown: i64,
+) -> Result<()> {
+    require!(fee_share_bps <= BASIS_POINTS_DENOMINATOR, Domin8Error::InvalidLpConfig);
+    require!(withdrawal_cooldown >= 0, Domin8Error::InvalidLpConfig);
+
+    let config = &mut ctx.accounts.config;
+    config.lp_fee_share_bps = fee_share_bps;
+    config.lp_withdrawal_cooldown = withdrawal_cooldown;
+
+    msg!("LP pool config: {} bps of house fee, {}s withdrawal cooldown", fee_share_bps, withdrawal_cooldown);
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::GameConfig;
+use crate::errors::Domin8Error;
+use crate::constants::{GAME_CONFIG_SEED, MAX_PLAYERS};
+
+#[derive(Accounts)]
+pub struct SetMinPlayers<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        constraint = authority.key() == config.authority @ Domin8Error::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Set the players needed to lock a round and how many waiting windows an
+/// under-filled round gets before it is refunded
+pub fn set_min_players(
+    ctx: Context<SetMinPlayers>,
+    min_players: u8,
+    max_window_extensions: u8,
+) -> Result<()> {
+    require!(
+        min_players >= 2 && min_players as usize <= MAX_PLAYERS,
+        Domin8Error::InvalidMinPlayersConfig
+    );
+
+    let config = &mut ctx.accounts.config;
+    config.min_players = min_players;
+    config.max_window_extensions = max_window_extensions;
+
+    msg!("Rounds need {} players, extended up to {} times", min_players, max_window_extensions);
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::state::GameConfig;
+use crate::errors::Domin8Error;
+use crate::constants::{GAME_CONFIG_SEED, BASIS_POINTS_DENOMINATOR};
+
+#[derive(Accounts)]
+pub struct SetNftBonusConfig<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        constraint = authority.key() == config.authority @ Domin8Error::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Set the cap on the champion NFT holder bonus (0 disables it)
+pub fn set_nft_bonus_config(ctx: Context<SetNftBonusConfig>, max_nft_bonus_bps: u16) -> Result<()> {
+    require!(max_nft_bonus_bps <= BASIS_POINTS_DENOMINATOR, Domin8Error::InvalidNftBonusConfig);
+
+    ctx.accounts.config.max_nft_bonus_bps = max_nft_bonus_bps;
+
+    msg!("Champion NFT bonus capped at {} bps", max_nft_bonus_bps);
+
+    Ok(())
+}
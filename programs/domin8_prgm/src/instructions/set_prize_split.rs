@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameConfig, GameRound, GameStatus, Room};
+use crate::errors::Domin8Error;
+use crate::constants::{GAME_CONFIG_SEED, GAME_ROUND_SEED, MAX_PRIZE_PLACES, ROOM_SEED};
+
+#[derive(Accounts)]
+pub struct SetPrizeSplit<'info> {
+    #[account(
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [ROOM_SEED, room.room_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub room: Account<'info, Room>,
+
+    /// The payout schedule can only change between rounds
+    #[account(
+        seeds = [GAME_ROUND_SEED, room.room_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = game_round.status == GameStatus::Idle @ Domin8Error::InvalidGameStatus
+    )]
+    pub game_round: Account<'info, GameRound>,
+
+    #[account(
+        constraint = authority.key() == config.authority @ Domin8Error::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Configure a room's top-N payout schedule, e.g. [7000, 2000, 1000] for 70/20/10.
+/// Only used while the room is in `GameMode::PrizeSplit`.
+pub fn set_prize_split(
+    ctx: Context<SetPrizeSplit>,
+    prize_split_basis_points: Vec<u16>,
+) -> Result<()> {
+    require!(
+        Room::is_valid_prize_split(&prize_split_basis_points),
+        Domin8Error::InvalidPrizeSplit
+    );
+
+    let mut table = [0u16; MAX_PRIZE_PLACES];
+    table[..prize_split_basis_points.len()].copy_from_slice(&prize_split_basis_points);
+    ctx.accounts.room.prize_split_basis_points = table;
+
+    msg!("Room {} prize split updated: {:?}", ctx.accounts.room.room_id, prize_split_basis_points);
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameConfig, RakebackReserve};
+use crate::errors::Domin8Error;
+use crate::constants::{GAME_CONFIG_SEED, MAX_RAKEBACK_BPS, RAKEBACK_RESERVE_SEED};
+
+#[derive(Accounts)]
+pub struct SetRakebackConfig<'info> {
+    #[account(
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [RAKEBACK_RESERVE_SEED],
+        bump
+    )]
+    pub rakeback_reserve: Account<'info, RakebackReserve>,
+
+    #[account(
+        constraint = authority.key() == config.authority @ Domin8Error::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Update the rakeback rate. Applies to loyalty points already accrued.
+pub fn set_rakeback_config(ctx: Context<SetRakebackConfig>, rakeback_bps: u16) -> Result<()> {
+    require!(
+        rakeback_bps <= MAX_RAKEBACK_BPS,
+        Domin8Error::InvalidRakebackConfig
+    );
+
+    ctx.accounts.rakeback_reserve.rakeback_bps = rakeback_bps;
+
+    msg!("Rakeback set to {} bps of the house fee", rakeback_bps);
+
+    Ok(())
+}
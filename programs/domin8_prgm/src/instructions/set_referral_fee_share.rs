@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::state::GameConfig;
+use crate::errors::Domin8Error;
+use crate::constants::{GAME_CONFIG_SEED, BASIS_POINTS_DENOMINATOR};
+
+#[derive(Accounts)]
+pub struct SetReferralFeeShare<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        constraint = authority.key() == config.authority @ Domin8Error::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Set the share of a referred player's house fee paid to their referrer
+pub fn set_referral_fee_share(ctx: Context<SetReferralFeeShare>, referral_fee_share_bps: u16) -> Result<()> {
+    require!(referral_fee_share_bps <= BASIS_POINTS_DENOMINATOR, Domin8Error::InvalidReferralConfig);
+
+    ctx.accounts.config.referral_fee_share_bps = referral_fee_share_bps;
+
+    msg!("Referral fee share: {} bps", referral_fee_share_bps);
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::GameConfig;
+use crate::errors::Domin8Error;
+use crate::constants::{GAME_CONFIG_SEED, BASIS_POINTS_DENOMINATOR};
+
+#[derive(Accounts)]
+pub struct SetRelayerConfig<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        constraint = authority.key() == config.authority @ Domin8Error::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Set the relayer reimbursed for sponsored bets, the reimbursement per bet
+/// and its cap as a share of the round's house fee
+pub fn set_relayer_config(
+    ctx: Context<SetRelayerConfig>,
+    relayer: Pubkey,
+    relayer_fee_per_bet: u64,
+    max_relayer_fee_bps: u16,
+) -> Result<()> {
+    require!(max_relayer_fee_bps <= BASIS_POINTS_DENOMINATOR, Domin8Error::InvalidRelayerConfig);
+
+    let config = &mut ctx.accounts.config;
+    config.relayer = relayer;
+    config.relayer_fee_per_bet = relayer_fee_per_bet;
+    config.max_relayer_fee_bps = max_relayer_fee_bps;
+
+    msg!("Relayer {}: {} lamports per bet, capped at {} bps of the house fee",
+         relayer, relayer_fee_per_bet, max_relayer_fee_bps);
+
+    Ok(())
+}
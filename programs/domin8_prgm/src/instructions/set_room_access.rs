@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameRound, GameStatus, Room, RoomAccess};
+use crate::errors::Domin8Error;
+use crate::constants::{GAME_ROUND_SEED, ROOM_SEED};
+
+#[derive(Accounts)]
+pub struct SetRoomAccess<'info> {
+    #[account(
+        mut,
+        seeds = [ROOM_SEED, room.room_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = room.is_private() @ Domin8Error::InvalidRoomAccess
+    )]
+    pub room: Account<'info, Room>,
+
+    /// Access can only change between rounds
+    #[account(
+        seeds = [GAME_ROUND_SEED, room.room_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = game_round.status == GameStatus::Idle @ Domin8Error::InvalidGameStatus
+    )]
+    pub game_round: Account<'info, GameRound>,
+
+    #[account(
+        constraint = creator.key() == room.creator @ Domin8Error::Unauthorized
+    )]
+    pub creator: Signer<'info>,
+}
+
+/// Replace a private room's allowlist or join code
+pub fn set_room_access(
+    ctx: Context<SetRoomAccess>,
+    access: RoomAccess,
+    join_code_hash: [u8; 32],
+    allowlist: Vec<Pubkey>,
+) -> Result<()> {
+    require!(
+        Room::is_valid_access(access, &join_code_hash, &allowlist),
+        Domin8Error::InvalidRoomAccess
+    );
+
+    let room = &mut ctx.accounts.room;
+    room.access = access;
+    room.join_code_hash = join_code_hash;
+    room.allowlist = allowlist;
+
+    msg!("Room {} access set to {:?} ({} allowlisted wallets)",
+         room.room_id, access, room.allowlist.len());
+
+    Ok(())
+}
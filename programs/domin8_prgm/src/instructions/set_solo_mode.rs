@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::state::GameConfig;
+use crate::errors::Domin8Error;
+use crate::constants::{GAME_CONFIG_SEED, BASIS_POINTS_DENOMINATOR};
+
+#[derive(Accounts)]
+pub struct SetSoloMode<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        constraint = authority.key() == config.authority @ Domin8Error::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Enable or disable solo-vs-house rounds and set their odds and exposure limit
+pub fn set_solo_mode(
+    ctx: Context<SetSoloMode>,
+    enabled: bool,
+    win_probability_bps: u16,
+    house_match_bps: u16,
+    max_exposure_bps: u16,
+) -> Result<()> {
+    require!(
+        win_probability_bps > 0 && win_probability_bps < BASIS_POINTS_DENOMINATOR,
+        Domin8Error::InvalidSoloOdds
+    );
+    require!(house_match_bps > 0, Domin8Error::InvalidSoloOdds);
+    require!(
+        max_exposure_bps > 0 && max_exposure_bps <= BASIS_POINTS_DENOMINATOR,
+        Domin8Error::InvalidSoloOdds
+    );
+
+    let config = &mut ctx.accounts.config;
+    config.solo_mode_enabled = enabled;
+    config.solo_win_probability_bps = win_probability_bps;
+    config.solo_house_match_bps = house_match_bps;
+    config.house_max_exposure_bps = max_exposure_bps;
+
+    msg!("Solo mode {}: win probability {} bps, house match {} bps, max exposure {} bps",
+         if enabled { "enabled" } else { "disabled" },
+         win_probability_bps, house_match_bps, max_exposure_bps);
+
+    Ok(())
+}
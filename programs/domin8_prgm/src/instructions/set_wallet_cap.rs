@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::state::GameConfig;
+use crate::errors::Domin8Error;
+use crate::constants::{GAME_CONFIG_SEED, BASIS_POINTS_DENOMINATOR};
+
+#[derive(Accounts)]
+pub struct SetWalletCap<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        constraint = authority.key() == config.authority @ Domin8Error::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Cap any one wallet's bets at `max_wallet_pot_share_bps` of the pot once the
+/// pot reaches `wallet_cap_min_pot`; a wallet betting alone is limited to that
+/// minimum pot. A zero share removes the cap; a cap needs a nonzero minimum pot
+/// so the opening bets of a round can still land.
+pub fn set_wallet_cap(
+    ctx: Context<SetWalletCap>,
+    max_wallet_pot_share_bps: u16,
+    wallet_cap_min_pot: u64,
+) -> Result<()> {
+    require!(
+        max_wallet_pot_share_bps < BASIS_POINTS_DENOMINATOR,
+        Domin8Error::InvalidWalletCapConfig
+    );
+    require!(
+        max_wallet_pot_share_bps == 0 || wallet_cap_min_pot > 0,
+        Domin8Error::InvalidWalletCapConfig
+    );
+
+    let config = &mut ctx.accounts.config;
+    config.max_wallet_pot_share_bps = max_wallet_pot_share_bps;
+    config.wallet_cap_min_pot = wallet_cap_min_pot;
+
+    msg!("Wallets capped at {} bps of pots from {}", max_wallet_pot_share_bps, wallet_cap_min_pot);
+
+    Ok(())
+}
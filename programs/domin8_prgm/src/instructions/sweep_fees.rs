@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{GameConfig, GameRound, Room};
+use crate::errors::Domin8Error;
+use crate::events::FeesSwept;
+use crate::constants::{GAME_CONFIG_SEED, GAME_ROUND_SEED, ROOM_SEED, VAULT_SEED};
+
+#[derive(Accounts)]
+pub struct SweepFees<'info> {
+    #[account(
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        seeds = [ROOM_SEED, room.room_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub room: Account<'info, Room>,
+
+    #[account(
+        mut,
+        seeds = [GAME_ROUND_SEED, room.room_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub game_round: Account<'info, GameRound>,
+
+    /// The room's vault PDA holding the accrued fees
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, room.room_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+
+    /// Treasury account for receiving the fees; only required when no fee split is configured
+    #[account(
+        mut,
+        constraint = treasury.key() == config.treasury @ Domin8Error::InvalidTreasury
+    )]
+    pub treasury: Option<SystemAccount<'info>>,
+
+    #[account(
+        constraint = authority.key() == config.authority @ Domin8Error::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Send a room's accrued house fees to the treasury, or split them between
+/// the fee recipients passed in remaining_accounts. Only the accrued fees are
+/// touched, never players' stakes, and the vault is kept rent exempt.
+pub fn sweep_fees<'info>(ctx: Context<'_, '_, '_, 'info, SweepFees<'info>>) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(vault.data_len());
+    let sweepable = vault.lamports().saturating_sub(rent_exempt_minimum);
+    let amount = ctx.accounts.game_round.accrued_fees.min(sweepable);
+    require!(amount > 0, Domin8Error::NoFeesToSweep);
+
+    let room_id = ctx.accounts.room.room_id.to_le_bytes();
+    let vault_bump = [ctx.bumps.vault];
+    let signer_seeds: &[&[&[u8]]] = &[&[VAULT_SEED, &room_id, &vault_bump]];
+    let transfer = |to: AccountInfo<'info>, lamports: u64| {
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: vault.to_account_info(),
+                    to,
+                },
+                signer_seeds,
+            ),
+            lamports,
+        )
+    };
+
+    if ctx.accounts.config.fee_split.is_empty() {
+        let treasury = ctx.accounts.treasury
+            .as_ref()
+            .ok_or(Domin8Error::InvalidTreasury)?;
+        transfer(treasury.to_account_info(), amount)?;
+    } else {
+        for (wallet, share) in ctx.accounts.config.split_treasury_fee(amount) {
+            if share == 0 {
+                continue;
+            }
+
+            let account = ctx.remaining_accounts
+                .iter()
+                .find(|account| account.key() == wallet)
+                .ok_or(Domin8Error::InvalidFeeRecipient)?;
+            transfer(account.clone(), share)?;
+            msg!("Fee recipient {} received {}", wallet, share);
+        }
+    }
+
+    let game_round = &mut ctx.accounts.game_round;
+    game_round.accrued_fees -= amount;
+
+    emit!(FeesSwept {
+        room_id: game_round.room_id,
+        amount,
+        remaining_accrued: game_round.accrued_fees,
+        swept_at: Clock::get()?.unix_timestamp,
+    });
+    msg!("Swept {} in fees from room {}", amount, game_round.room_id);
+
+    Ok(())
+}
@@ -1,16 +1,24 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use orao_solana_vrf::cpi::accounts::RequestV2;
 use orao_solana_vrf::program::OraoVrf;
 use orao_solana_vrf::cpi::request_v2;
-use crate::state::{GameRound, GameConfig, GameStatus};
+use crate::state::{GameRound, GameConfig, GameStatus, HouseVault, PlayerBalance, PlayerStats, Room};
 use crate::errors::Domin8Error;
-use crate::constants::{GAME_ROUND_SEED, GAME_CONFIG_SEED};
+use crate::constants::{GAME_ROUND_SEED, GAME_CONFIG_SEED, HOUSE_VAULT_SEED, PLAYER_BALANCE_SEED, ROOM_SEED, VAULT_SEED, VAULT_TOKEN_SEED};
+use super::unified_resolve_and_distribute::TokenPayout;
 
 #[derive(Accounts)]
 pub struct UnifiedProgressToResolution<'info> {
+    #[account(
+        seeds = [ROOM_SEED, room.room_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub room: Account<'info, Room>,
+    
     #[account(
         mut,
-        seeds = [GAME_ROUND_SEED],
+        seeds = [GAME_ROUND_SEED, room.room_id.to_le_bytes().as_ref()],
         bump
     )]
     pub game_round: Account<'info, GameRound>,
@@ -46,12 +54,50 @@ pub struct UnifiedProgressToResolution<'info> {
     #[account(mut)]
     pub vrf_request: AccountInfo<'info>,
     
+    /// House bankroll, only required when a solo player is matched by the house
+    #[account(
+        mut,
+        seeds = [HOUSE_VAULT_SEED],
+        bump
+    )]
+    pub house_vault: Option<Account<'info, HouseVault>>,
+    
+    /// The room's vault PDA, paid out when an under-filled round is refunded
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, room.room_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+    
+    // SPL token rooms only, for refunds - players' token accounts go in remaining_accounts
+    #[account(
+        address = room.bet_mint @ Domin8Error::InvalidBetMint
+    )]
+    pub bet_mint: Option<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_TOKEN_SEED, room.room_id.to_le_bytes().as_ref(), room.bet_mint.as_ref()],
+        bump
+    )]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    
     pub system_program: Program<'info, System>,
 }
 
 /// UNIFIED INSTRUCTION: Progress game from Waiting directly to AwaitingWinnerRandomness
-/// This replaces the old progress_to_resolution + separate VRF request
-pub fn unified_progress_to_resolution(ctx: Context<UnifiedProgressToResolution>) -> Result<()> {
+/// This replaces the old progress_to_resolution + separate VRF request.
+/// A round below `config.min_players` at the end of its waiting window is
+/// extended up to `config.max_window_extensions` times, then every stake is
+/// refunded: players' wallets (or token accounts in token rooms), the
+/// `PlayerBalance` of anyone who bet Game Coins and, optionally, their
+/// `PlayerStats` accounts go in remaining_accounts.
+pub fn unified_progress_to_resolution<'info>(
+    ctx: Context<'_, '_, '_, 'info, UnifiedProgressToResolution<'info>>,
+) -> Result<()> {
     let game_round = &mut ctx.accounts.game_round;
     
     // Validate game state
@@ -60,56 +106,366 @@ pub fn unified_progress_to_resolution(ctx: Context<UnifiedProgressToResolution>)
         Domin8Error::InvalidGameStatus
     );
     
+    // Betting must have closed, including any soft-close extension
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now >= game_round.close_at,
+        Domin8Error::WaitingWindowOpen
+    );
+    
     let player_count = game_round.players.len();
     msg!("Unified progress: transitioning game {} with {} players", game_round.round_id, player_count);
     
+    // A lone player can play the house in SOL rooms with solo mode on (the bankroll only backs SOL)
+    let solo_eligible = player_count == 1
+        && ctx.accounts.config.solo_mode_enabled
+        && !ctx.accounts.room.is_token_pool();
+    if player_count < ctx.accounts.config.min_players as usize && !solo_eligible {
+        if game_round.extend_waiting_window(
+            now,
+            ctx.accounts.room.duration_config.waiting_phase_duration as i64,
+            ctx.accounts.config.max_window_extensions,
+        ) {
+            msg!("Only {} of {} players - waiting window extended ({} of {})",
+                 player_count, ctx.accounts.config.min_players,
+                 game_round.window_extensions, ctx.accounts.config.max_window_extensions);
+            return Ok(());
+        }
+        
+        let token_payout = if ctx.accounts.room.is_token_pool() {
+            match (
+                &ctx.accounts.bet_mint,
+                &ctx.accounts.vault_token_account,
+                &ctx.accounts.token_program,
+            ) {
+                (Some(bet_mint), Some(vault_token_account), Some(token_program)) => Some(TokenPayout {
+                    bet_mint,
+                    vault_token_account,
+                    token_program,
+                    vault: &ctx.accounts.vault,
+                    room_id: ctx.accounts.room.room_id,
+                    vault_bump: ctx.bumps.vault,
+                }),
+                _ => return err!(Domin8Error::TokenAccountsRequired),
+            }
+        } else {
+            None
+        };
+        
+        refund_players(
+            game_round,
+            &ctx.accounts.room,
+            &ctx.accounts.vault,
+            token_payout.as_ref(),
+            ctx.remaining_accounts,
+            ctx.program_id,
+        )?;
+        game_round.reset_waiting();
+        
+        msg!("Room {} round {} refunded after {} extensions",
+             game_round.room_id, game_round.round_id, ctx.accounts.config.max_window_extensions);
+        return Ok(());
+    }
+    
     match player_count {
         0 => {
             return Err(Domin8Error::InvalidGameStatus.into());
         },
         1 => {
-            // Single player - immediate finish with refund
-            game_round.status = GameStatus::Finished;
-            game_round.winner = game_round.players[0].wallet;
-            msg!("Single player game - immediate finish with refund");
-            return Ok(());
-        },
-        2..=64 => {
-            // Multi-player game - request ORAO VRF and transition to AwaitingWinnerRandomness
-            
-            // Generate deterministic seed for this game round
-            let seed: [u8; 32] = generate_vrf_seed(game_round.round_id);
-            game_round.vrf_seed = seed;
-            game_round.vrf_request_pubkey = ctx.accounts.vrf_request.key();
-            
-            // Make CPI call to ORAO VRF
-            let cpi_program = ctx.accounts.vrf_program.to_account_info();
-            let cpi_accounts = RequestV2 {
-                payer: ctx.accounts.crank.to_account_info(),
-                network_state: ctx.accounts.network_state.to_account_info(),
-                treasury: ctx.accounts.treasury.to_account_info(),
-                request: ctx.accounts.vrf_request.to_account_info(),
-                system_program: ctx.accounts.system_program.to_account_info(),
-            };
+            // Solo player vs house - reserve a matching stake from the bankroll.
+            // The lamports move at resolution; until then the stake is held back from
+            // other rooms' matches and from bankroll withdrawals.
+            let config = &ctx.accounts.config;
+            let house_vault = ctx.accounts.house_vault
+                .as_mut()
+                .ok_or(Domin8Error::HouseVaultRequired)?;
             
-            let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-            request_v2(cpi_ctx, seed)?;
+            let house_match = config.calculate_house_match(game_round.initial_pot);
+            let available = house_vault.available_bankroll(&house_vault.to_account_info())?;
+            require!(
+                house_match > 0 && house_match <= config.max_house_exposure(available),
+                Domin8Error::HouseExposureExceeded
+            );
             
-            // Update game state to AwaitingWinnerRandomness
-            game_round.status = GameStatus::AwaitingWinnerRandomness;
-            game_round.randomness_fulfilled = false;
+            house_vault.total_matched = house_vault.total_matched
+                .checked_add(house_match)
+                .ok_or(Domin8Error::ArithmeticOverflow)?;
+            house_vault.reserved_stake = house_vault.reserved_stake
+                .checked_add(house_match)
+                .ok_or(Domin8Error::ArithmeticOverflow)?;
+            game_round.house_stake = house_match;
+            game_round.solo_win_probability_bps = config.solo_win_probability_bps;
             
-            msg!("ORAO VRF requested - game {} now awaiting winner randomness", game_round.round_id);
+            msg!("Solo round - house matches {} lamports at {} bps win probability",
+                 house_match, game_round.solo_win_probability_bps);
+        },
+        2..=64 => {
+            // Multi-player game - weighted winner selection
         },
         _ => return Err(Domin8Error::InvalidGameStatus.into()),
     }
     
+    // Request ORAO VRF and transition to AwaitingWinnerRandomness
+    
+    // Generate deterministic seed for this game round
+    let seed: [u8; 32] = generate_vrf_seed(game_round.room_id, game_round.round_id);
+    game_round.vrf_seed = seed;
+    game_round.vrf_request_pubkey = ctx.accounts.vrf_request.key();
+    
+    // Make CPI call to ORAO VRF
+    let cpi_program = ctx.accounts.vrf_program.to_account_info();
+    let cpi_accounts = RequestV2 {
+        payer: ctx.accounts.crank.to_account_info(),
+        network_state: ctx.accounts.network_state.to_account_info(),
+        treasury: ctx.accounts.treasury.to_account_info(),
+        request: ctx.accounts.vrf_request.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+    };
+    
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    request_v2(cpi_ctx, seed)?;
+    
+    // Update game state to AwaitingWinnerRandomness
+    game_round.status = GameStatus::AwaitingWinnerRandomness;
+    game_round.randomness_fulfilled = false;
+    
+    msg!("ORAO VRF requested - game {} now awaiting winner randomness", game_round.round_id);
+    
+    Ok(())
+}
+
+/// Return every stake in an under-filled round and undo its statistics.
+/// Game Coins stakes go back to the player's balance, the rest to the wallet.
+fn refund_players<'info>(
+    game_round: &GameRound,
+    room: &Room,
+    vault: &SystemAccount<'info>,
+    token_payout: Option<&TokenPayout<'_, 'info>>,
+    accounts: &[AccountInfo<'info>],
+    program_id: &Pubkey,
+) -> Result<()> {
+    for player in game_round.players.iter().filter(|p| p.total_bet > 0) {
+        if let Some(token_payout) = token_payout {
+            let token_account = token_payout.find_token_account(accounts, &player.wallet)?;
+            token_payout.transfer(token_account, player.total_bet)?;
+            continue;
+        }
+        
+        require!(
+            vault.lamports() >= player.total_bet,
+            Domin8Error::InsufficientFunds
+        );
+        
+        let from_balance = player.balance_bet.min(player.total_bet);
+        if from_balance > 0 {
+            let (balance_key, _) = Pubkey::find_program_address(
+                &[PLAYER_BALANCE_SEED, player.wallet.as_ref()],
+                program_id,
+            );
+            let account = accounts
+                .iter()
+                .find(|account| account.key() == balance_key)
+                .ok_or(Domin8Error::PlayerBalanceRequired)?;
+            let mut player_balance = PlayerBalance::try_deserialize(&mut &account.data.borrow()[..])?;
+            player_balance.balance = player_balance.balance.saturating_add(from_balance);
+            player_balance.try_serialize(&mut &mut account.data.borrow_mut()[..])?;
+            
+            // Direct lamport transfer (player balance is program-owned)
+            **vault.try_borrow_mut_lamports()? -= from_balance;
+            **account.try_borrow_mut_lamports()? += from_balance;
+        }
+        
+        let from_wallet = player.total_bet - from_balance;
+        if from_wallet > 0 {
+            let account = accounts
+                .iter()
+                .find(|account| account.key() == player.wallet)
+                .ok_or(Domin8Error::PlayerNotFound)?;
+            
+            // Direct lamport transfer
+            **vault.try_borrow_mut_lamports()? -= from_wallet;
+            **account.try_borrow_mut_lamports()? += from_wallet;
+        }
+    }
+    
+    // The round never happened: it is not played or wagered.
+    // Each stats account is only undone once, however often it is passed in.
+    let mut refunded_stats: Vec<Pubkey> = Vec::new();
+    for account in accounts.iter().filter(|account| account.owner == program_id) {
+        if refunded_stats.contains(account.key) {
+            continue;
+        }
+        let Ok(mut player_stats) = PlayerStats::try_deserialize(&mut &account.data.borrow()[..]) else {
+            continue;
+        };
+        let Some(player) = game_round.find_player(&player_stats.player) else {
+            continue;
+        };
+        
+        player_stats.rounds_played = player_stats.rounds_played.saturating_sub(1);
+        if !room.is_token_pool() {
+            player_stats.total_wagered = player_stats.total_wagered.saturating_sub(player.total_bet);
+        }
+        player_stats.loyalty_points = player_stats.loyalty_points.saturating_sub(player.loyalty_points);
+        player_stats.try_serialize(&mut &mut account.data.borrow_mut()[..])?;
+        refunded_stats.push(account.key());
+    }
+    
     Ok(())
 }
 
-fn generate_vrf_seed(round_id: u64) -> [u8; 32] {
+fn generate_vrf_seed(room_id: u64, round_id: u64) -> [u8; 32] {
     let mut seed = [0u8; 32];
     seed[0..8].copy_from_slice(&round_id.to_le_bytes());
     seed[8..16].copy_from_slice(&Clock::get().unwrap().unix_timestamp.to_le_bytes());
+    // Keeps requests unique when several rooms progress the same round number in the same second
+    seed[16..24].copy_from_slice(&room_id.to_le_bytes());
     seed
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL;
+    use anchor_lang::system_program;
+    use crate::state::PlayerEntry;
+
+    const WAITING_DURATION: i64 = 60;
+    const MAX_EXTENSIONS: u8 = 3;
+
+    fn waiting_round(wallet: Pubkey, stake: u64, loyalty_points: u64) -> GameRound {
+        let mut game_round = GameRound::deserialize(&mut &[0u8; GameRound::LEN][..]).unwrap();
+        game_round.status = GameStatus::Waiting;
+        game_round.close_at = 100;
+        game_round.initial_pot = stake;
+        game_round.players.push(PlayerEntry {
+            wallet,
+            total_bet: stake,
+            timestamp: 0,
+            balance_bet: 0,
+            sponsored_bets: 0,
+            loyalty_points,
+        });
+        game_round
+    }
+
+    #[test]
+    fn lone_player_is_refunded_after_the_last_extension() {
+        let wallet = Pubkey::new_unique();
+        let stake = LAMPORTS_PER_SOL;
+        let loyalty_points = stake / 20;
+        let mut game_round = waiting_round(wallet, stake, loyalty_points);
+        let room = Room::deserialize(&mut &[0u8; Room::LEN][..]).unwrap();
+
+        // Every waiting window ends with one player: extended until the limit
+        let mut now = game_round.close_at;
+        for extension in 1..=MAX_EXTENSIONS {
+            assert!(game_round.extend_waiting_window(now, WAITING_DURATION, MAX_EXTENSIONS));
+            assert_eq!(game_round.window_extensions, extension);
+            assert_eq!(game_round.close_at, now + WAITING_DURATION);
+            now = game_round.close_at;
+        }
+        assert!(!game_round.extend_waiting_window(now, WAITING_DURATION, MAX_EXTENSIONS));
+
+        // Then the stake goes back and the round's statistics are undone
+        let vault_key = Pubkey::new_unique();
+        let mut vault_lamports = 1_000_000 + stake;
+        let mut vault_data = [];
+        let vault_info = AccountInfo::new(
+            &vault_key, false, true, &mut vault_lamports, &mut vault_data, &system_program::ID, false, 0,
+        );
+        let vault = SystemAccount::try_from(&vault_info).unwrap();
+
+        let mut wallet_lamports = 0;
+        let mut wallet_data = [];
+        let wallet_info = AccountInfo::new(
+            &wallet, false, true, &mut wallet_lamports, &mut wallet_data, &system_program::ID, false, 0,
+        );
+
+        let mut player_stats = PlayerStats::deserialize(&mut &[0u8; PlayerStats::LEN][..]).unwrap();
+        player_stats.player = wallet;
+        player_stats.rounds_played = 1;
+        player_stats.total_wagered = stake;
+        player_stats.loyalty_points = loyalty_points;
+        let mut stats_data = Vec::new();
+        player_stats.try_serialize(&mut stats_data).unwrap();
+        let stats_key = Pubkey::new_unique();
+        let mut stats_lamports = 1_000_000;
+        let stats_info = AccountInfo::new(
+            &stats_key, false, true, &mut stats_lamports, &mut stats_data, &crate::ID, false, 0,
+        );
+
+        refund_players(&game_round, &room, &vault, None, &[wallet_info.clone(), stats_info.clone()], &crate::ID).unwrap();
+        game_round.reset_waiting();
+
+        assert_eq!(wallet_info.lamports(), stake);
+        assert_eq!(vault_info.lamports(), 1_000_000);
+        let player_stats = PlayerStats::try_deserialize(&mut &stats_info.data.borrow()[..]).unwrap();
+        assert_eq!(player_stats.rounds_played, 0);
+        assert_eq!(player_stats.total_wagered, 0);
+        assert_eq!(player_stats.loyalty_points, 0);
+
+        assert_eq!(game_round.status, GameStatus::Idle);
+        assert!(game_round.players.is_empty());
+        assert_eq!(game_round.window_extensions, 0);
+        assert_eq!(game_round.initial_pot, 0);
+    }
+
+    #[test]
+    fn game_coins_part_of_a_stake_goes_back_to_the_balance() {
+        let wallet = Pubkey::new_unique();
+        let stake = 3 * LAMPORTS_PER_SOL;
+        let mut game_round = waiting_round(wallet, stake, 0);
+        game_round.players[0].balance_bet = LAMPORTS_PER_SOL;
+        let room = Room::deserialize(&mut &[0u8; Room::LEN][..]).unwrap();
+
+        let vault_key = Pubkey::new_unique();
+        let mut vault_lamports = stake;
+        let mut vault_data = [];
+        let vault_info = AccountInfo::new(
+            &vault_key, false, true, &mut vault_lamports, &mut vault_data, &system_program::ID, false, 0,
+        );
+        let vault = SystemAccount::try_from(&vault_info).unwrap();
+
+        let mut wallet_lamports = 0;
+        let mut wallet_data = [];
+        let wallet_info = AccountInfo::new(
+            &wallet, false, true, &mut wallet_lamports, &mut wallet_data, &system_program::ID, false, 0,
+        );
+
+        let player_balance = PlayerBalance { player: wallet, balance: 0, total_deposited: 0, total_withdrawn: 0 };
+        let mut balance_data = Vec::new();
+        player_balance.try_serialize(&mut balance_data).unwrap();
+        let (balance_key, _) = Pubkey::find_program_address(&[PLAYER_BALANCE_SEED, wallet.as_ref()], &crate::ID);
+        let mut balance_lamports = 0;
+        let balance_info = AccountInfo::new(
+            &balance_key, false, true, &mut balance_lamports, &mut balance_data, &crate::ID, false, 0,
+        );
+
+        refund_players(&game_round, &room, &vault, None, &[wallet_info.clone(), balance_info.clone()], &crate::ID).unwrap();
+
+        assert_eq!(wallet_info.lamports(), 2 * LAMPORTS_PER_SOL);
+        assert_eq!(balance_info.lamports(), LAMPORTS_PER_SOL);
+        let player_balance = PlayerBalance::try_deserialize(&mut &balance_info.data.borrow()[..]).unwrap();
+        assert_eq!(player_balance.balance, LAMPORTS_PER_SOL);
+        assert_eq!(vault_info.lamports(), 0);
+    }
+
+    #[test]
+    fn missing_player_balance_fails_the_refund() {
+        let wallet = Pubkey::new_unique();
+        let mut game_round = waiting_round(wallet, LAMPORTS_PER_SOL, 0);
+        game_round.players[0].balance_bet = LAMPORTS_PER_SOL;
+        let room = Room::deserialize(&mut &[0u8; Room::LEN][..]).unwrap();
+
+        let vault_key = Pubkey::new_unique();
+        let mut vault_lamports = LAMPORTS_PER_SOL;
+        let mut vault_data = [];
+        let vault_info = AccountInfo::new(
+            &vault_key, false, true, &mut vault_lamports, &mut vault_data, &system_program::ID, false, 0,
+        );
+        let vault = SystemAccount::try_from(&vault_info).unwrap();
+
+        let result = refund_players(&game_round, &room, &vault, None, &[], &crate::ID);
+        assert_eq!(result.unwrap_err(), Domin8Error::PlayerBalanceRequired.into());
+    }
+}
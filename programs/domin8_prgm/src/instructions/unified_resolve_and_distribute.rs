@@ -1,13 +1,23 @@
 use anchor_lang::prelude::*;
-use crate::state::{GameRound, GameConfig, GameStatus, PlayerEntry};
+use anchor_spl::metadata::MetadataAccount;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use orao_solana_vrf::state::RandomnessAccountData;
+use crate::state::{GameRound, GameConfig, GameStatus, HouseVault, Jackpot, PlayerEntry, PlayerStats, PrizePlacement, RakebackReserve, Referral, ReferrerAccount, Room, RoundResult, Season, Tournament, TournamentStatus};
 use crate::errors::Domin8Error;
-use crate::constants::{GAME_ROUND_SEED, GAME_CONFIG_SEED, VAULT_SEED};
+use crate::constants::{GAME_ROUND_SEED, GAME_CONFIG_SEED, ROOM_SEED, VAULT_SEED, VAULT_TOKEN_SEED, ROUND_RESULT_SEED, HOUSE_VAULT_SEED, JACKPOT_SEED, RAKEBACK_RESERVE_SEED, CHAMPION_COLLECTION_SEED, BASIS_POINTS_DENOMINATOR};
+use crate::replay::{derive_replay_seed, jackpot_randomness, placement_order, winner_randomness};
 
 #[derive(Accounts)]
 pub struct UnifiedResolveAndDistribute<'info> {
+    #[account(
+        seeds = [ROOM_SEED, room.room_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub room: Account<'info, Room>,
+    
     #[account(
         mut,
-        seeds = [GAME_ROUND_SEED],
+        seeds = [GAME_ROUND_SEED, room.room_id.to_le_bytes().as_ref()],
         bump
     )]
     pub game_round: Account<'info, GameRound>,
@@ -18,16 +28,34 @@ pub struct UnifiedResolveAndDistribute<'info> {
     )]
     pub config: Account<'info, GameConfig>,
     
-    /// The vault PDA that holds game funds
+    /// The room's vault PDA that holds game funds
     #[account(
         mut,
-        seeds = [VAULT_SEED],
+        seeds = [VAULT_SEED, room.room_id.to_le_bytes().as_ref()],
         bump
     )]
     pub vault: SystemAccount<'info>,
     
-    /// The crank authority
+    /// Permanent record of this round's outcome and replay data
+    #[account(
+        init,
+        payer = crank,
+        space = RoundResult::space(
+            game_round.players.len(),
+            game_round.players.len().min(room.prize_places())
+        ),
+        seeds = [
+            ROUND_RESULT_SEED,
+            room.room_id.to_le_bytes().as_ref(),
+            game_round.round_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub round_result: Account<'info, RoundResult>,
+    
+    /// The crank authority (pays rent for the round result record)
     #[account(
+        mut,
         constraint = crank.key() == config.authority @ Domin8Error::Unauthorized
     )]
     pub crank: Signer<'info>,
@@ -36,21 +64,110 @@ pub struct UnifiedResolveAndDistribute<'info> {
     /// CHECK: This account was created by ORAO VRF program
     pub vrf_request: AccountInfo<'info>,
     
-    /// Treasury account for receiving house fees
+    /// House bankroll, only required to settle solo-vs-house rounds
+    #[account(
+        mut,
+        seeds = [HOUSE_VAULT_SEED],
+        bump
+    )]
+    pub house_vault: Option<Account<'info, HouseVault>>,
+    
+    /// Progressive jackpot, funded from the pot once it has been initialized
+    #[account(
+        mut,
+        seeds = [JACKPOT_SEED],
+        bump
+    )]
+    pub jackpot: Option<Account<'info, Jackpot>>,
+    
+    /// Rakeback reserve, funded from the house fee in native SOL rooms once initialized
     #[account(
         mut,
-        constraint = treasury.key() == config.treasury @ Domin8Error::InvalidTreasury
+        seeds = [RAKEBACK_RESERVE_SEED],
+        bump
     )]
-    pub treasury: SystemAccount<'info>,
+    pub rakeback_reserve: Option<Account<'info, RakebackReserve>>,
+    
+    /// Configured relayer, reimbursed for the round's sponsored bets
+    #[account(
+        mut,
+        constraint = relayer.key() == config.relayer @ Domin8Error::InvalidRelayerConfig
+    )]
+    pub relayer: Option<SystemAccount<'info>>,
+    
+    // SPL token rooms only - winners' token accounts go in remaining_accounts
+    #[account(
+        address = room.bet_mint @ Domin8Error::InvalidBetMint
+    )]
+    pub bet_mint: Option<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_TOKEN_SEED, room.room_id.to_le_bytes().as_ref(), room.bet_mint.as_ref()],
+        bump
+    )]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// Treasury's token account for receiving house fees in the bet mint
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == config.treasury @ Domin8Error::InvalidTreasury,
+        constraint = treasury_token_account.mint == room.bet_mint @ Domin8Error::InvalidBetMint
+    )]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    
+    /// Required while the room's round counts towards a tournament
+    #[account(
+        mut,
+        constraint = tournament.key() == game_round.tournament @ Domin8Error::InvalidTournament
+    )]
+    pub tournament: Option<Account<'info, Tournament>>,
+    
+    /// Current season, funded from the house fee while it is active
+    #[account(
+        mut,
+        constraint = season.key() == config.current_season @ Domin8Error::InvalidSeasonConfig
+    )]
+    pub season: Option<Account<'info, Season>>,
     
     pub system_program: Program<'info, System>,
 }
 
 /// UNIFIED INSTRUCTION: Resolve winner using ORAO VRF and immediately distribute winnings
-/// This replaces the old resolve_winner + distribute_winnings flow
-pub fn unified_resolve_and_distribute(ctx: Context<UnifiedResolveAndDistribute>) -> Result<()> {
+/// This replaces the old resolve_winner + distribute_winnings flow.
+/// Champion NFTs held by the winner can be passed in remaining_accounts as
+/// (token account, metadata) pairs to earn the NFT holder bonus, and the
+/// players' `PlayerStats` accounts to settle their statistics, plus any
+/// `Referral` and `ReferrerAccount` pairs to pay referrers. In token rooms with
+/// a fee split configured, every recipient's token account must be passed too.
+pub fn unified_resolve_and_distribute<'info>(
+    ctx: Context<'_, '_, '_, 'info, UnifiedResolveAndDistribute<'info>>,
+) -> Result<()> {
     let game_round = &mut ctx.accounts.game_round;
     
+    // Token room accounts, resolved once up front (None for native SOL rooms)
+    let token_payout = if ctx.accounts.room.is_token_pool() {
+        match (
+            &ctx.accounts.bet_mint,
+            &ctx.accounts.vault_token_account,
+            &ctx.accounts.token_program,
+        ) {
+            (Some(bet_mint), Some(vault_token_account), Some(token_program)) => Some(TokenPayout {
+                bet_mint,
+                vault_token_account,
+                token_program,
+                vault: &ctx.accounts.vault,
+                room_id: ctx.accounts.room.room_id,
+                vault_bump: ctx.bumps.vault,
+            }),
+            _ => return err!(Domin8Error::TokenAccountsRequired),
+        }
+    } else {
+        None
+    };
+    
     require!(
         game_round.status == GameStatus::AwaitingWinnerRandomness,
         Domin8Error::InvalidGameStatus
@@ -62,68 +179,420 @@ pub fn unified_resolve_and_distribute(ctx: Context<UnifiedResolveAndDistribute>)
     );
     
     // 1. READ RANDOMNESS FROM ORAO VRF
-    let randomness = read_orao_randomness(&ctx.accounts.vrf_request)?;
+    let fulfilled_randomness = read_orao_randomness(&ctx.accounts.vrf_request)?;
+    let randomness = winner_randomness(&fulfilled_randomness);
+    let replay_seed = derive_replay_seed(game_round.round_id, &fulfilled_randomness);
     msg!("Retrieved ORAO VRF randomness: {}", randomness);
     
     // 2. SELECT WINNER USING VERIFIED RANDOMNESS
-    require!(
-        game_round.players.len() >= 2,
-        Domin8Error::InvalidGameStatus
-    );
-    
-    let player_refs: Vec<&PlayerEntry> = game_round.players.iter().collect();
-    let winner_wallet = select_weighted_winner(&player_refs, randomness)?;
+    let placed_wallets: Vec<Pubkey> = if game_round.is_solo() {
+        // Solo round - the player takes the matched pot or the house keeps it
+        let house_vault = ctx.accounts.house_vault
+            .as_mut()
+            .ok_or(Domin8Error::HouseVaultRequired)?;
+        
+        // Release the reservation and bring the house match into the game vault
+        let house_stake = game_round.house_stake;
+        house_vault.reserved_stake = house_vault.reserved_stake.saturating_sub(house_stake);
+        require!(
+            house_vault.available_bankroll(&house_vault.to_account_info())? >= house_stake,
+            Domin8Error::InsufficientBankroll
+        );
+        **house_vault.to_account_info().try_borrow_mut_lamports()? -= house_stake;
+        **ctx.accounts.vault.try_borrow_mut_lamports()? += house_stake;
+        
+        let winner = if solo_player_wins(randomness, game_round.solo_win_probability_bps) {
+            game_round.players[0].wallet
+        } else {
+            house_vault.key()
+        };
+        vec![winner]
+    } else {
+        require!(
+            game_round.players.len() >= 2,
+            Domin8Error::InvalidGameStatus
+        );
+        
+        let player_refs: Vec<&PlayerEntry> = game_round.players.iter().collect();
+        let winner = select_weighted_winner(&player_refs, randomness)?;
+        
+        // Places after the winner are the next weighted draws without replacement
+        placement_order(&replay_seed, &game_round.players, &winner)
+            .into_iter()
+            .take(ctx.accounts.room.prize_places())
+            .collect()
+    };
+    let winner_wallet = placed_wallets[0];
     
     game_round.winner = winner_wallet;
     msg!("Winner selected: {}", winner_wallet);
     
     // 3. CALCULATE WINNINGS
-    let total_pot = game_round.initial_pot;
-    let house_fee = (total_pot as u128)
-        .checked_mul(ctx.accounts.config.house_fee_basis_points as u128)
-        .ok_or(Domin8Error::ArithmeticOverflow)?
-        .checked_div(10000)
-        .ok_or(Domin8Error::ArithmeticOverflow)? as u64;
-    let winner_payout = total_pot.saturating_sub(house_fee);
+    let total_pot = game_round.total_pot();
+    let mut house_fee = ctx.accounts.room.calculate_house_fee(&ctx.accounts.config, total_pot);
+    // Part of the fee charged on the players' stakes rather than the house's solo match;
+    // loyalty points and rakeback funding are both based on it
+    let player_house_fee = if total_pot > 0 {
+        ((house_fee as u128 * game_round.initial_pot as u128) / total_pot as u128) as u64
+    } else {
+        0
+    };
+    // The jackpot is SOL-denominated, so token rooms do not contribute to it
+    let jackpot_contribution = match &ctx.accounts.jackpot {
+        Some(jackpot) if token_payout.is_none() => jackpot.calculate_contribution(total_pot),
+        _ => 0,
+    };
+    let distributable = total_pot
+        .saturating_sub(house_fee)
+        .saturating_sub(jackpot_contribution);
     
-    msg!("Distributing: {} to winner, {} to house", winner_payout, house_fee);
+    let mut payouts = ctx.accounts.room.calculate_prize_payouts(distributable, placed_wallets.len());
     
-    // 4. DISTRIBUTE WINNINGS - Use direct lamport manipulation to avoid lifetime issues
-    if winner_payout > 0 {
-        // Find winner account in remaining_accounts
-        let mut winner_found = false;
-        for account in ctx.remaining_accounts {
-            if account.key() == winner_wallet {
-                let vault_lamports = ctx.accounts.vault.lamports();
-                require!(
-                    vault_lamports >= winner_payout,
-                    Domin8Error::InsufficientFunds
-                );
-                
-                // Direct lamport transfer
-                **ctx.accounts.vault.try_borrow_mut_lamports()? -= winner_payout;
-                **account.try_borrow_mut_lamports()? += winner_payout;
-                winner_found = true;
-                break;
-            }
-        }
-        require!(winner_found, Domin8Error::InvalidWinnerAccount);
+    // Champion NFT holder bonus, paid to the winner out of the house fee
+    let nft_bonus = if game_round.find_player(&winner_wallet).is_some() {
+        let nft_count = count_champion_nfts(ctx.remaining_accounts, &winner_wallet, ctx.program_id);
+        ctx.accounts.config.calculate_nft_bonus(payouts[0], nft_count).min(house_fee)
+    } else {
+        0
+    };
+    if nft_bonus > 0 {
+        payouts[0] = payouts[0].saturating_add(nft_bonus);
+        house_fee = house_fee.saturating_sub(nft_bonus);
+        msg!("Champion NFT bonus: {} to {}", nft_bonus, winner_wallet);
     }
     
-    // Transfer house fee to treasury
-    if house_fee > 0 {
+    let placements: Vec<PrizePlacement> = placed_wallets
+        .iter()
+        .zip(payouts.iter())
+        .map(|(wallet, payout)| PrizePlacement { wallet: *wallet, payout: *payout })
+        .collect();
+    let winner_payout = payouts[0];
+    
+    msg!("Distributing: {} to winner, {} to {} other places, {} to house",
+         winner_payout, distributable.saturating_add(nft_bonus).saturating_sub(winner_payout), placements.len() - 1, house_fee);
+    
+    // 4. DISTRIBUTE WINNINGS - Use direct lamport manipulation to avoid lifetime issues
+    for placement in placements.iter().filter(|p| p.payout > 0) {
+        if let Some(token_payout) = &token_payout {
+            // Token rooms pay the placed wallet's token account for the bet mint
+            let token_account = token_payout.find_token_account(ctx.remaining_accounts, &placement.wallet)?;
+            token_payout.transfer(token_account, placement.payout)?;
+            continue;
+        }
+        
         let vault_lamports = ctx.accounts.vault.lamports();
         require!(
-            vault_lamports >= house_fee,
+            vault_lamports >= placement.payout,
             Domin8Error::InsufficientFunds
         );
         
+        // The house vault collects the pot when it beats a solo player
+        if let Some(house_vault) = ctx.accounts.house_vault.as_mut() {
+            if house_vault.key() == placement.wallet {
+                **ctx.accounts.vault.try_borrow_mut_lamports()? -= placement.payout;
+                **house_vault.to_account_info().try_borrow_mut_lamports()? += placement.payout;
+                house_vault.total_won = house_vault.total_won
+                    .checked_add(placement.payout)
+                    .ok_or(Domin8Error::ArithmeticOverflow)?;
+                continue;
+            }
+        }
+        
+        // Find placed wallet in remaining_accounts
+        let account = ctx.remaining_accounts
+            .iter()
+            .find(|account| account.key() == placement.wallet)
+            .ok_or(Domin8Error::InvalidWinnerAccount)?;
+        
         // Direct lamport transfer
-        **ctx.accounts.vault.try_borrow_mut_lamports()? -= house_fee;
-        **ctx.accounts.treasury.try_borrow_mut_lamports()? += house_fee;
+        **ctx.accounts.vault.try_borrow_mut_lamports()? -= placement.payout;
+        **account.try_borrow_mut_lamports()? += placement.payout;
+    }
+    
+    // Season prize pool share of the house fee (SOL rooms only)
+    let now = Clock::get()?.unix_timestamp;
+    if let Some(season) = ctx.accounts.season.as_mut().filter(|s| s.is_active(now) && token_payout.is_none()) {
+        let season_fee = season.calculate_contribution(house_fee);
+        if season_fee > 0 {
+            require!(
+                ctx.accounts.vault.lamports() >= season_fee,
+                Domin8Error::InsufficientFunds
+            );
+            
+            **ctx.accounts.vault.try_borrow_mut_lamports()? -= season_fee;
+            **season.to_account_info().try_borrow_mut_lamports()? += season_fee;
+            season.prize_pool = season.prize_pool
+                .checked_add(season_fee)
+                .ok_or(Domin8Error::ArithmeticOverflow)?;
+            house_fee = house_fee.saturating_sub(season_fee);
+            
+            msg!("Season {} prize pool +{}", season.season_id, season_fee);
+        }
+    }
+    
+    // Referrers earn a share of the house fee generated by the players they referred (SOL rooms only)
+    if ctx.accounts.config.referral_fee_share_bps > 0 && token_payout.is_none() && game_round.initial_pot > 0 {
+        let mut referred_players: Vec<Pubkey> = Vec::new();
+        let mut referral_fees = 0u64;
+        for account in ctx.remaining_accounts.iter().filter(|account| account.owner == ctx.program_id) {
+            let Ok(referral) = Referral::try_deserialize(&mut &account.data.borrow()[..]) else {
+                continue;
+            };
+            let Some(player) = game_round.find_player(&referral.player) else {
+                continue;
+            };
+            if referred_players.contains(&referral.player) {
+                continue;
+            }
+            
+            // The player's part of the house fee, by share of the player pot
+            let player_house_fee = ((house_fee as u128 * player.total_bet as u128)
+                / game_round.initial_pot as u128) as u64;
+            let referral_fee = ctx.accounts.config.calculate_referral_fee(player_house_fee);
+            if referral_fee == 0 {
+                continue;
+            }
+            require!(
+                ctx.accounts.vault.lamports() >= referral_fee,
+                Domin8Error::InsufficientFunds
+            );
+            
+            if credit_referrer(ctx.remaining_accounts, ctx.program_id, &referral.referrer, referral_fee)? {
+                **ctx.accounts.vault.try_borrow_mut_lamports()? -= referral_fee;
+                referred_players.push(referral.player);
+                referral_fees = referral_fees.saturating_add(referral_fee);
+                msg!("Referrer {} earned {} from {}", referral.referrer, referral_fee, referral.player);
+            }
+        }
+        house_fee = house_fee.saturating_sub(referral_fees);
+    }
+    
+    // Rakeback reserve backs the loyalty points this round's bets accrued (SOL rooms only)
+    if let Some(rakeback_reserve) = ctx.accounts.rakeback_reserve.as_mut().filter(|_| token_payout.is_none()) {
+        let rakeback_fee = rakeback_reserve.calculate_rakeback(player_house_fee).min(house_fee);
+        if rakeback_fee > 0 {
+            require!(
+                ctx.accounts.vault.lamports() >= rakeback_fee,
+                Domin8Error::InsufficientFunds
+            );
+            
+            **ctx.accounts.vault.try_borrow_mut_lamports()? -= rakeback_fee;
+            **rakeback_reserve.to_account_info().try_borrow_mut_lamports()? += rakeback_fee;
+            rakeback_reserve.total_funded = rakeback_reserve.total_funded
+                .checked_add(rakeback_fee)
+                .ok_or(Domin8Error::ArithmeticOverflow)?;
+            house_fee = house_fee.saturating_sub(rakeback_fee);
+            
+            msg!("Rakeback reserve +{}", rakeback_fee);
+        }
+    }
+    
+    // Relayer reimbursement for sponsored bets (SOL rooms only)
+    if let Some(relayer) = ctx.accounts.relayer.as_ref().filter(|_| token_payout.is_none()) {
+        let relayer_fee = ctx.accounts.config.calculate_relayer_fee(game_round.sponsored_bets, house_fee);
+        if relayer_fee > 0 {
+            require!(
+                ctx.accounts.vault.lamports() >= relayer_fee,
+                Domin8Error::InsufficientFunds
+            );
+            
+            **ctx.accounts.vault.try_borrow_mut_lamports()? -= relayer_fee;
+            **relayer.try_borrow_mut_lamports()? += relayer_fee;
+            house_fee = house_fee.saturating_sub(relayer_fee);
+            
+            msg!("Relayer reimbursed {} for {} sponsored bets", relayer_fee, game_round.sponsored_bets);
+        }
     }
     
-    // 5. RESET GAME STATE FOR NEXT ROUND
+    // LP share of the house fee goes to the house vault, raising the LP share price
+    let lp_fee = if ctx.accounts.config.lp_fee_share_bps > 0 && token_payout.is_none() {
+        let house_vault = ctx.accounts.house_vault
+            .as_mut()
+            .ok_or(Domin8Error::HouseVaultRequired)?;
+        
+        if house_vault.total_shares > 0 {
+            let lp_fee = ctx.accounts.config.calculate_lp_fee(house_fee);
+            require!(
+                ctx.accounts.vault.lamports() >= lp_fee,
+                Domin8Error::InsufficientFunds
+            );
+            
+            **ctx.accounts.vault.try_borrow_mut_lamports()? -= lp_fee;
+            **house_vault.to_account_info().try_borrow_mut_lamports()? += lp_fee;
+            house_vault.total_lp_fees = house_vault.total_lp_fees
+                .checked_add(lp_fee)
+                .ok_or(Domin8Error::ArithmeticOverflow)?;
+            lp_fee
+        } else {
+            0
+        }
+    } else {
+        0
+    };
+    
+    // Private room creators earn a share of what would go to the treasury
+    let creator_fee = if ctx.accounts.room.is_private() {
+        ctx.accounts.config.calculate_creator_fee(house_fee.saturating_sub(lp_fee))
+    } else {
+        0
+    };
+    if creator_fee > 0 {
+        let creator = ctx.accounts.room.creator;
+        if let Some(token_payout) = &token_payout {
+            let token_account = token_payout.find_token_account(ctx.remaining_accounts, &creator)?;
+            token_payout.transfer(token_account, creator_fee)?;
+        } else {
+            require!(
+                ctx.accounts.vault.lamports() >= creator_fee,
+                Domin8Error::InsufficientFunds
+            );
+            
+            let account = ctx.remaining_accounts
+                .iter()
+                .find(|account| account.key() == creator)
+                .ok_or(Domin8Error::InvalidRoomCreatorAccount)?;
+            
+            **ctx.accounts.vault.try_borrow_mut_lamports()? -= creator_fee;
+            **account.try_borrow_mut_lamports()? += creator_fee;
+        }
+        
+        msg!("Room creator {} earned {}", creator, creator_fee);
+    }
+    
+    // The rest of the house fee: SOL accrues in the room vault until `sweep_fees`;
+    // token rooms pay the treasury, or the fee split recipients, directly
+    let treasury_fee = house_fee.saturating_sub(lp_fee).saturating_sub(creator_fee);
+    if let (Some(token_payout), true) = (&token_payout, treasury_fee > 0) {
+        token_payout.pay_treasury_fee(
+            &ctx.accounts.config,
+            ctx.accounts.treasury_token_account.as_ref(),
+            ctx.remaining_accounts,
+            treasury_fee,
+        )?;
+    } else if treasury_fee > 0 {
+        game_round.accrued_fees = game_round.accrued_fees
+            .checked_add(treasury_fee)
+            .ok_or(Domin8Error::ArithmeticOverflow)?;
+        msg!("House fee {} accrued, {} awaiting sweep", treasury_fee, game_round.accrued_fees);
+    }
+    
+    // 5. PROGRESSIVE JACKPOT
+    let mut jackpot_payout = 0u64;
+    if let Some(jackpot) = ctx.accounts.jackpot.as_mut().filter(|_| token_payout.is_none()) {
+        if jackpot_contribution > 0 {
+            require!(
+                ctx.accounts.vault.lamports() >= jackpot_contribution,
+                Domin8Error::InsufficientFunds
+            );
+            
+            **ctx.accounts.vault.try_borrow_mut_lamports()? -= jackpot_contribution;
+            **jackpot.to_account_info().try_borrow_mut_lamports()? += jackpot_contribution;
+            jackpot.total_contributed = jackpot.total_contributed
+                .checked_add(jackpot_contribution)
+                .ok_or(Domin8Error::ArithmeticOverflow)?;
+        }
+        
+        let rent_exempt_minimum = Rent::get()?.minimum_balance(jackpot.to_account_info().data_len());
+        
+        // Only a player can hit the jackpot, never the house in a solo round
+        let winner_is_player = game_round.players.iter().any(|p| p.wallet == winner_wallet);
+        if winner_is_player && jackpot.is_hit(jackpot_randomness(&fulfilled_randomness)) {
+            jackpot_payout = jackpot.to_account_info().lamports().saturating_sub(rent_exempt_minimum);
+            
+            if jackpot_payout > 0 {
+                let account = ctx.remaining_accounts
+                    .iter()
+                    .find(|account| account.key() == winner_wallet)
+                    .ok_or(Domin8Error::InvalidWinnerAccount)?;
+                
+                **jackpot.to_account_info().try_borrow_mut_lamports()? -= jackpot_payout;
+                **account.try_borrow_mut_lamports()? += jackpot_payout;
+                
+                jackpot.total_paid = jackpot.total_paid
+                    .checked_add(jackpot_payout)
+                    .ok_or(Domin8Error::ArithmeticOverflow)?;
+                jackpot.last_winner = winner_wallet;
+                jackpot.last_payout = jackpot_payout;
+                jackpot.last_won_round = game_round.round_id;
+                
+                msg!("JACKPOT! {} lamports to {}", jackpot_payout, winner_wallet);
+            }
+        }
+        
+        jackpot.balance = jackpot.to_account_info().lamports().saturating_sub(rent_exempt_minimum);
+    }
+    
+    // 6. TOURNAMENT STANDINGS
+    if game_round.is_tournament_round() {
+        let tournament = ctx.accounts.tournament
+            .as_mut()
+            .ok_or(Domin8Error::InvalidTournament)?;
+        
+        if let Some(winner_bet) = game_round.find_player(&winner_wallet).map(|p| p.total_bet) {
+            if let Some(standing) = tournament.find_standing_mut(&winner_wallet) {
+                let points = Tournament::calculate_points(winner_bet, total_pot);
+                standing.points = standing.points.saturating_add(points);
+                standing.rounds_won = standing.rounds_won.saturating_add(1);
+                msg!("Tournament {}: {} earns {} points", tournament.tournament_id, winner_wallet, points);
+            }
+        }
+        
+        tournament.rounds_played = tournament.rounds_played.saturating_add(1);
+        tournament.last_activity_at = now;
+        if tournament.rounds_played >= tournament.total_rounds {
+            tournament.status = TournamentStatus::Finished;
+            // Release the room for regular play
+            game_round.tournament = Pubkey::default();
+            msg!("Tournament {} finished after {} rounds", tournament.tournament_id, tournament.rounds_played);
+        }
+    }
+    
+    // 7. RECORD RESULT AND REPLAY DATA
+    let round_result = &mut ctx.accounts.round_result;
+    round_result.room_id = game_round.room_id;
+    round_result.round_id = game_round.round_id;
+    round_result.winner = winner_wallet;
+    round_result.players = game_round.players.clone();
+    round_result.bet_mint = ctx.accounts.room.bet_mint;
+    round_result.total_pot = total_pot;
+    round_result.winner_payout = winner_payout;
+    round_result.house_fee = house_fee;
+    round_result.nft_bonus = nft_bonus;
+    round_result.placements = placements;
+    round_result.jackpot_contribution = jackpot_contribution;
+    round_result.jackpot_payout = jackpot_payout;
+    round_result.randomness = fulfilled_randomness;
+    round_result.replay_seed = replay_seed;
+    round_result.resolved_at = now;
+    
+    // 8. PLAYER STATISTICS
+    let round_result_key = ctx.accounts.round_result.key();
+    let counts_lamports = token_payout.is_none();
+    for account in ctx.remaining_accounts.iter().filter(|account| account.owner == ctx.program_id) {
+        let Ok(mut player_stats) = PlayerStats::try_deserialize(&mut &account.data.borrow()[..]) else {
+            continue;
+        };
+        if player_stats.last_settled == round_result_key {
+            continue;
+        }
+        if game_round.find_player(&player_stats.player).is_none() {
+            continue;
+        }
+        
+        let won = player_stats.player == winner_wallet;
+        let mut payout: u64 = ctx.accounts.round_result.placements
+            .iter()
+            .filter(|p| p.wallet == player_stats.player)
+            .map(|p| p.payout)
+            .sum();
+        if won {
+            payout = payout.saturating_add(jackpot_payout);
+        }
+        player_stats.record_result(round_result_key, won, if counts_lamports { payout } else { 0 });
+        player_stats.try_serialize(&mut &mut account.data.borrow_mut()[..])?;
+    }
+    
+    // 9. RESET GAME STATE FOR NEXT ROUND
     game_round.status = GameStatus::Idle;
     game_round.randomness_fulfilled = true;
     game_round.round_id = game_round.round_id
@@ -131,31 +600,181 @@ pub fn unified_resolve_and_distribute(ctx: Context<UnifiedResolveAndDistribute>)
         .ok_or(Domin8Error::ArithmeticOverflow)?; // Increment for next game
     game_round.players.clear();
     game_round.initial_pot = 0;
+    game_round.house_stake = 0;
+    game_round.solo_win_probability_bps = 0;
     game_round.start_timestamp = 0;
+    game_round.sponsored_bets = 0;
+    game_round.window_extensions = 0;
+    game_round.close_at = 0;
+    game_round.late_bet_extension = 0;
     
-    msg!("Game {} completed and reset - ready for round {}", 
+    msg!("Room {} game {} completed and reset - ready for round {}", 
+         game_round.room_id,
          game_round.round_id.saturating_sub(1), 
          game_round.round_id);
     
     Ok(())
 }
 
-fn read_orao_randomness(vrf_account: &AccountInfo) -> Result<u64> {
-    // Read fulfilled randomness from ORAO VRF account
+fn read_orao_randomness(vrf_account: &AccountInfo) -> Result<[u8; 64]> {
+    // Read fulfilled randomness from ORAO VRF account (V1 or V2 layout)
     let data = vrf_account.data.borrow();
+    let request = RandomnessAccountData::try_deserialize(&mut &data[..])
+        .map_err(|_| Domin8Error::InvalidVrfAccount)?;
+    
+    // Full 64-byte randomness is only available once the request is fulfilled
+    let randomness = request
+        .fulfilled_randomness()
+        .ok_or(Domin8Error::RandomnessNotFulfilled)?;
     
-    // ORAO VRF account structure - randomness is at offset 8+32 = 40
-    require!(data.len() >= 40 + 8, Domin8Error::InvalidVrfAccount);
+    Ok(*randomness)
+}
+
+/// Accounts needed to pay out of a token room's vault token account
+pub(crate) struct TokenPayout<'a, 'info> {
+    pub(crate) bet_mint: &'a InterfaceAccount<'info, Mint>,
+    pub(crate) vault_token_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub(crate) token_program: &'a Interface<'info, TokenInterface>,
+    pub(crate) vault: &'a SystemAccount<'info>,
+    pub(crate) room_id: u64,
+    pub(crate) vault_bump: u8,
+}
+
+impl<'info> TokenPayout<'_, 'info> {
+    /// Find `wallet`'s token account for the bet mint among the remaining accounts
+    pub(crate) fn find_token_account(&self, accounts: &[AccountInfo<'info>], wallet: &Pubkey) -> Result<AccountInfo<'info>> {
+        accounts
+            .iter()
+            .find(|account| {
+                account.owner == self.token_program.key
+                    && TokenAccount::try_deserialize(&mut &account.data.borrow()[..])
+                        .is_ok_and(|token_account| {
+                            token_account.owner == *wallet && token_account.mint == self.bet_mint.key()
+                        })
+            })
+            .cloned()
+            .ok_or(Domin8Error::InvalidWinnerAccount.into())
+    }
+
+    /// Transfer tokens out of the vault token account, signed by the vault PDA
+    pub(crate) fn transfer(&self, to: AccountInfo<'info>, amount: u64) -> Result<()> {
+        let room_id = self.room_id.to_le_bytes();
+        let vault_bump = [self.vault_bump];
+        let signer_seeds: &[&[&[u8]]] = &[&[VAULT_SEED, &room_id, &vault_bump]];
+        
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                TransferChecked {
+                    from: self.vault_token_account.to_account_info(),
+                    mint: self.bet_mint.to_account_info(),
+                    to,
+                    authority: self.vault.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+            self.bet_mint.decimals,
+        )
+    }
+
+    /// Pay a fee owed to the treasury in the bet mint: to the treasury's token
+    /// account, or split between the fee recipients' token accounts found among
+    /// the remaining accounts
+    pub(crate) fn pay_treasury_fee(
+        &self,
+        config: &GameConfig,
+        treasury_token_account: Option<&InterfaceAccount<'info, TokenAccount>>,
+        accounts: &[AccountInfo<'info>],
+        amount: u64,
+    ) -> Result<()> {
+        if config.fee_split.is_empty() {
+            let treasury_token_account = treasury_token_account.ok_or(Domin8Error::TokenAccountsRequired)?;
+            return self.transfer(treasury_token_account.to_account_info(), amount);
+        }
+
+        for (wallet, share) in config.split_treasury_fee(amount) {
+            if share == 0 {
+                continue;
+            }
+
+            let token_account = self
+                .find_token_account(accounts, &wallet)
+                .map_err(|_| Domin8Error::InvalidFeeRecipient)?;
+            self.transfer(token_account, share)?;
+            msg!("Fee recipient {} received {}", wallet, share);
+        }
+
+        Ok(())
+    }
+}
+
+/// Credit `amount` lamports to a referrer's earnings account among the remaining accounts.
+/// Returns false if the account was not provided.
+fn credit_referrer(accounts: &[AccountInfo], program_id: &Pubkey, referrer: &Pubkey, amount: u64) -> Result<bool> {
+    for account in accounts.iter().filter(|account| account.owner == program_id) {
+        let Ok(mut referrer_account) = ReferrerAccount::try_deserialize(&mut &account.data.borrow()[..]) else {
+            continue;
+        };
+        if referrer_account.referrer != *referrer {
+            continue;
+        }
+        
+        referrer_account.claimable = referrer_account.claimable
+            .checked_add(amount)
+            .ok_or(Domin8Error::ArithmeticOverflow)?;
+        referrer_account.total_earned = referrer_account.total_earned.saturating_add(amount);
+        referrer_account.try_serialize(&mut &mut account.data.borrow_mut()[..])?;
+        **account.try_borrow_mut_lamports()? += amount;
+        return Ok(true);
+    }
     
-    // Check if randomness is fulfilled (flag at offset 8+32+8 = 48)
-    let fulfilled = data[48] != 0;
-    require!(fulfilled, Domin8Error::RandomnessNotFulfilled);
+    Ok(false)
+}
+
+/// Count distinct champion NFTs held by `winner` among the remaining accounts.
+/// Each NFT needs its Token Metadata account, verified in the champion
+/// collection, next to a token account of the same mint owned by the winner.
+fn count_champion_nfts(accounts: &[AccountInfo], winner: &Pubkey, program_id: &Pubkey) -> usize {
+    let metadata_accounts: Vec<MetadataAccount> = accounts
+        .iter()
+        .filter(|account| *account.owner == anchor_spl::metadata::ID)
+        .filter_map(|account| MetadataAccount::try_deserialize(&mut &account.data.borrow()[..]).ok())
+        .collect();
+    if metadata_accounts.is_empty() {
+        return 0;
+    }
     
-    // Read 8-byte randomness value
-    let randomness_bytes: [u8; 8] = data[40..48].try_into()
-        .map_err(|_| Domin8Error::InvalidVrfAccount)?;
+    let (collection_mint, _) = Pubkey::find_program_address(&[CHAMPION_COLLECTION_SEED], program_id);
+    let mut counted: Vec<Pubkey> = Vec::new();
+    for metadata in metadata_accounts {
+        let in_collection = metadata.collection
+            .as_ref()
+            .is_some_and(|collection| collection.verified && collection.key == collection_mint);
+        if !in_collection || counted.contains(&metadata.mint) {
+            continue;
+        }
+        
+        let held = accounts.iter().any(|account| {
+            *account.owner == anchor_spl::token::ID
+                && anchor_spl::token::TokenAccount::try_deserialize(&mut &account.data.borrow()[..])
+                    .is_ok_and(|token_account| {
+                        token_account.mint == metadata.mint
+                            && token_account.owner == *winner
+                            && token_account.amount == 1
+                    })
+        });
+        if held {
+            counted.push(metadata.mint);
+        }
+    }
     
-    Ok(u64::from_le_bytes(randomness_bytes))
+    counted.len()
+}
+
+/// Solo round outcome: the player wins with the snapshotted probability
+fn solo_player_wins(randomness: u64, win_probability_bps: u16) -> bool {
+    randomness % (BASIS_POINTS_DENOMINATOR as u64) < win_probability_bps as u64
 }
 
 /// Select winner weighted by bet amounts
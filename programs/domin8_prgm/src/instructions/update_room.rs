@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameConfig, GameRound, GameDurationConfig, GameMode, GameStatus, Room};
+use crate::errors::Domin8Error;
+use crate::constants::{GAME_CONFIG_SEED, GAME_ROUND_SEED, ROOM_SEED};
+use super::create_room::validate_room_params;
+
+#[derive(Accounts)]
+pub struct UpdateRoom<'info> {
+    #[account(
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [ROOM_SEED, room.room_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub room: Account<'info, Room>,
+
+    /// Room economics can only change between rounds
+    #[account(
+        seeds = [GAME_ROUND_SEED, room.room_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = game_round.status == GameStatus::Idle @ Domin8Error::InvalidGameStatus
+    )]
+    pub game_round: Account<'info, GameRound>,
+
+    #[account(
+        constraint = authority.key() == config.authority @ Domin8Error::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Change a room's bet limits, fee, waiting duration and game mode
+pub fn update_room(
+    ctx: Context<UpdateRoom>,
+    min_bet: u64,
+    max_bet: u64,
+    house_fee_basis_points: u16,
+    waiting_phase_duration: u64,
+    game_mode: GameMode,
+) -> Result<()> {
+    validate_room_params(min_bet, max_bet, house_fee_basis_points)?;
+
+    let room = &mut ctx.accounts.room;
+    room.min_bet = min_bet;
+    room.max_bet = max_bet;
+    room.house_fee_basis_points = house_fee_basis_points;
+    room.duration_config = GameDurationConfig { waiting_phase_duration };
+    room.game_mode = game_mode;
+
+    msg!("Room {} updated: bets {}-{}, fee {} bps, {}s waiting, {:?}",
+         room.room_id, min_bet, max_bet, house_fee_basis_points, waiting_phase_duration, game_mode);
+
+    Ok(())
+}
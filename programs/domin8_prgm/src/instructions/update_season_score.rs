@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::{ChampionNft, PlayerStats, Season, SeasonStats};
+use crate::errors::Domin8Error;
+use crate::constants::{PLAYER_STATS_SEED, SEASON_SEED, SEASON_STATS_SEED};
+
+#[derive(Accounts)]
+pub struct UpdateSeasonScore<'info> {
+    #[account(
+        mut,
+        seeds = [SEASON_SEED, season.season_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub season: Account<'info, Season>,
+
+    #[account(
+        mut,
+        seeds = [SEASON_STATS_SEED, season.key().as_ref(), season_stats.player.as_ref()],
+        bump
+    )]
+    pub season_stats: Account<'info, SeasonStats>,
+
+    #[account(
+        seeds = [PLAYER_STATS_SEED, season_stats.player.as_ref()],
+        bump
+    )]
+    pub player_stats: Account<'info, PlayerStats>,
+}
+
+/// Recompute a wallet's season points and update the leaderboard.
+/// Permissionless while the season is active; champion NFTs won during the
+/// season are added when their `ChampionNft` records are passed (writable) in
+/// remaining_accounts, and each is marked so it only ever counts once.
+pub fn update_season_score(ctx: Context<UpdateSeasonScore>) -> Result<()> {
+    let season_key = ctx.accounts.season.key();
+    let season = &mut ctx.accounts.season;
+    require!(season.is_active(Clock::get()?.unix_timestamp), Domin8Error::SeasonNotActive);
+
+    let season_stats = &mut ctx.accounts.season_stats;
+    for account in ctx.remaining_accounts.iter().filter(|account| account.owner == ctx.program_id) {
+        let Ok(mut champion_nft) = ChampionNft::try_deserialize(&mut &account.data.borrow()[..]) else {
+            continue;
+        };
+        if champion_nft.counts_for_season(&season_key, season, &season_stats.player) {
+            champion_nft.counted_season = season_key;
+            champion_nft.try_serialize(&mut &mut account.data.borrow_mut()[..])?;
+            season_stats.champion_nfts = season_stats.champion_nfts.saturating_add(1);
+        }
+    }
+
+    season_stats.points = season_stats.calculate_points(&ctx.accounts.player_stats, &season.points_formula);
+    season.update_leaderboard(season_stats.player, season_stats.points);
+
+    msg!("Season {}: {} has {} points", season.season_id, season_stats.player, season_stats.points);
+
+    Ok(())
+}
@@ -0,0 +1,204 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{GameConfig, GameRound, GameStatus, PlayerBalance, PlayerStats, Room};
+use crate::constants::*;
+use crate::errors::Domin8Error;
+use super::unified_resolve_and_distribute::TokenPayout;
+
+#[derive(Accounts)]
+pub struct WithdrawBet<'info> {
+    #[account(
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+    
+    #[account(
+        seeds = [ROOM_SEED, room.room_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub room: Account<'info, Room>,
+    
+    /// Stakes can only be withdrawn while the round is still accepting bets
+    #[account(
+        mut,
+        seeds = [GAME_ROUND_SEED, room.room_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = game_round.status == GameStatus::Waiting @ Domin8Error::InvalidGameStatus
+    )]
+    pub game_round: Account<'info, GameRound>,
+    
+    /// The room's vault PDA that holds game funds
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, room.room_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [PLAYER_STATS_SEED, player.key().as_ref()],
+        bump
+    )]
+    pub player_stats: Box<Account<'info, PlayerStats>>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    /// Required when part of the stake was bet from Game Coins; that part goes back to the balance
+    #[account(
+        mut,
+        seeds = [PLAYER_BALANCE_SEED, player.key().as_ref()],
+        bump
+    )]
+    pub player_balance: Option<Account<'info, PlayerBalance>>,
+    
+    // SPL token rooms only - omit all five for native SOL bets
+    #[account(
+        address = room.bet_mint @ Domin8Error::InvalidBetMint
+    )]
+    pub bet_mint: Option<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        mut,
+        constraint = player_token_account.mint == room.bet_mint @ Domin8Error::InvalidBetMint
+    )]
+    pub player_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_TOKEN_SEED, room.room_id.to_le_bytes().as_ref(), room.bet_mint.as_ref()],
+        bump
+    )]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// Treasury's token account for receiving the penalty in the bet mint; only
+    /// required when no fee split is configured
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == config.treasury @ Domin8Error::InvalidTreasury,
+        constraint = treasury_token_account.mint == room.bet_mint @ Domin8Error::InvalidBetMint
+    )]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+/// Pull back `amount` of the player's stake before the round locks, less the
+/// configured penalty. A partial withdrawal must leave at least the room's
+/// minimum bet; withdrawing everything removes the player from the round.
+/// Stake bet from Game Coins is returned to the player's balance first and
+/// bears the penalty before any wallet-funded stake does. The penalty is a
+/// house fee: SOL stays in the vault until `sweep_fees`, while token rooms pay
+/// the treasury, or the fee split recipients passed in remaining_accounts.
+pub fn withdraw_bet<'info>(ctx: Context<'_, '_, '_, 'info, WithdrawBet<'info>>, amount: u64) -> Result<()> {
+    let player_key = ctx.accounts.player.key();
+    let game_round = &mut ctx.accounts.game_round;
+    let room = &ctx.accounts.room;
+    require!(
+        game_round.is_betting_open(Clock::get()?.unix_timestamp),
+        Domin8Error::BettingPhaseClosed
+    );
+    
+    let player = game_round
+        .find_player_mut(&player_key)
+        .ok_or(Domin8Error::PlayerNotFound)?;
+    require!(
+        amount > 0 && amount <= player.total_bet,
+        Domin8Error::InvalidBetAmount
+    );
+    let remaining_bet = player.total_bet - amount;
+    require!(
+        remaining_bet == 0 || remaining_bet >= room.min_bet,
+        Domin8Error::BetTooSmall
+    );
+    
+    // The withdrawn share of the stake gives back the loyalty points it accrued
+    let loyalty_points = ((player.loyalty_points as u128 * amount as u128) / player.total_bet as u128) as u64;
+    player.loyalty_points -= loyalty_points;
+    player.total_bet = remaining_bet;
+    
+    let from_balance = amount.min(player.balance_bet);
+    player.balance_bet -= from_balance;
+    let sponsored_bets = player.sponsored_bets;
+    
+    let penalty = ctx.accounts.config.calculate_withdrawal_penalty(amount);
+    let refund = amount - penalty;
+    let balance_refund = from_balance.saturating_sub(penalty);
+    let wallet_refund = refund - balance_refund;
+    
+    if room.is_token_pool() {
+        let (Some(bet_mint), Some(player_token_account), Some(vault_token_account), Some(token_program)) = (
+            &ctx.accounts.bet_mint,
+            &ctx.accounts.player_token_account,
+            &ctx.accounts.vault_token_account,
+            &ctx.accounts.token_program,
+        ) else {
+            return err!(Domin8Error::TokenAccountsRequired);
+        };
+        
+        let token_payout = TokenPayout {
+            bet_mint,
+            vault_token_account,
+            token_program,
+            vault: &ctx.accounts.vault,
+            room_id: room.room_id,
+            vault_bump: ctx.bumps.vault,
+        };
+        
+        token_payout.transfer(player_token_account.to_account_info(), refund)?;
+        if penalty > 0 {
+            token_payout.pay_treasury_fee(
+                &ctx.accounts.config,
+                ctx.accounts.treasury_token_account.as_ref(),
+                ctx.remaining_accounts,
+                penalty,
+            )?;
+        }
+    } else {
+        require!(
+            ctx.accounts.vault.lamports() >= amount,
+            Domin8Error::InsufficientFunds
+        );
+        
+        // Direct lamport transfers out of the vault; the penalty stays behind as an accrued fee
+        **ctx.accounts.vault.try_borrow_mut_lamports()? -= refund;
+        **ctx.accounts.player.to_account_info().try_borrow_mut_lamports()? += wallet_refund;
+        if balance_refund > 0 {
+            let player_balance = ctx.accounts.player_balance
+                .as_mut()
+                .ok_or(Domin8Error::PlayerBalanceRequired)?;
+            **player_balance.to_account_info().try_borrow_mut_lamports()? += balance_refund;
+            player_balance.balance = player_balance.balance.saturating_add(balance_refund);
+        }
+        game_round.accrued_fees = game_round.accrued_fees
+            .checked_add(penalty)
+            .ok_or(Domin8Error::ArithmeticOverflow)?;
+        
+        // Withdrawn stake no longer counts as wagered
+        let player_stats = &mut ctx.accounts.player_stats;
+        player_stats.total_wagered = player_stats.total_wagered.saturating_sub(amount);
+        player_stats.loyalty_points = player_stats.loyalty_points.saturating_sub(loyalty_points);
+    }
+    
+    game_round.initial_pot = game_round.initial_pot.saturating_sub(amount);
+    if remaining_bet == 0 {
+        // The relayer is only reimbursed for bets still in the round
+        game_round.players.retain(|p| p.wallet != player_key);
+        game_round.sponsored_bets = game_round.sponsored_bets.saturating_sub(sponsored_bets as u32);
+        let player_stats = &mut ctx.accounts.player_stats;
+        player_stats.rounds_played = player_stats.rounds_played.saturating_sub(1);
+        msg!("Player {} left the round", player_key);
+    }
+    
+    // Nobody left - the round goes back to waiting for its first player
+    if game_round.players.is_empty() {
+        game_round.reset_waiting();
+        msg!("Room {} round {} is empty and back to idle", game_round.room_id, game_round.round_id);
+    }
+    
+    msg!("Withdrew {} from {}: {} refunded, {} penalty", amount, player_key, refund, penalty);
+    
+    Ok(())
+}
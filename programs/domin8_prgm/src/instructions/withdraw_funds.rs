@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameConfig, PlayerBalance};
+use crate::errors::Domin8Error;
+use crate::constants::{GAME_CONFIG_SEED, PLAYER_BALANCE_SEED};
+
+#[derive(Accounts)]
+pub struct WithdrawFunds<'info> {
+    #[account(
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [PLAYER_BALANCE_SEED, player.key().as_ref()],
+        bump
+    )]
+    pub player_balance: Account<'info, PlayerBalance>,
+
+    /// Treasury account for receiving the withdrawal fee
+    #[account(
+        mut,
+        constraint = treasury.key() == config.treasury @ Domin8Error::InvalidTreasury
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub player: Signer<'info>,
+}
+
+/// Convert `coins` Game Coins back into SOL, less the withdrawal fee
+pub fn withdraw_funds(ctx: Context<WithdrawFunds>, coins: u64) -> Result<()> {
+    let lamports = ctx.accounts.config
+        .coins_to_lamports(coins)
+        .ok_or(Domin8Error::InvalidCoinRate)?;
+    require!(
+        lamports <= ctx.accounts.player_balance.balance,
+        Domin8Error::InsufficientBalance
+    );
+
+    let fee = PlayerBalance::calculate_withdrawal_fee(lamports);
+    require!(lamports > fee, Domin8Error::WithdrawalTooSmall);
+    let net_amount = lamports - fee;
+
+    // Direct lamport transfers (player balance is program-owned)
+    let balance_info = ctx.accounts.player_balance.to_account_info();
+    **balance_info.try_borrow_mut_lamports()? -= lamports;
+    **ctx.accounts.player.to_account_info().try_borrow_mut_lamports()? += net_amount;
+    **ctx.accounts.treasury.try_borrow_mut_lamports()? += fee;
+
+    let player_balance = &mut ctx.accounts.player_balance;
+    player_balance.balance -= lamports;
+    player_balance.total_withdrawn = player_balance.total_withdrawn.saturating_add(lamports);
+
+    msg!("Withdrew {} coins: {} lamports to {}, {} fee", coins, net_amount, player_balance.player, fee);
+
+    Ok(())
+}
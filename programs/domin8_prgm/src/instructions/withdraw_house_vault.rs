@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameConfig, HouseVault};
+use crate::errors::Domin8Error;
+use crate::constants::{GAME_CONFIG_SEED, HOUSE_VAULT_SEED};
+
+#[derive(Accounts)]
+pub struct WithdrawHouseVault<'info> {
+    #[account(
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    /// Once LPs hold shares the bankroll is theirs and leaves only through LP withdrawals
+    #[account(
+        mut,
+        seeds = [HOUSE_VAULT_SEED],
+        bump,
+        constraint = house_vault.total_shares == 0 @ Domin8Error::LpSharesOutstanding
+    )]
+    pub house_vault: Account<'info, HouseVault>,
+
+    #[account(
+        mut,
+        constraint = authority.key() == config.authority @ Domin8Error::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Withdraw SOL from the house bankroll back to the authority.
+/// Stakes reserved by open solo rounds are excluded from what can be withdrawn.
+pub fn withdraw_house_vault(ctx: Context<WithdrawHouseVault>, amount: u64) -> Result<()> {
+    require!(amount > 0, Domin8Error::InvalidBetAmount);
+
+    let available = ctx.accounts.house_vault.available_bankroll(&ctx.accounts.house_vault.to_account_info())?;
+    require!(amount <= available, Domin8Error::InsufficientBankroll);
+
+    // Direct lamport transfer (house vault is program-owned)
+    **ctx.accounts.house_vault.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.authority.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    let house_vault = &mut ctx.accounts.house_vault;
+    house_vault.total_withdrawn = house_vault.total_withdrawn
+        .checked_add(amount)
+        .ok_or(Domin8Error::ArithmeticOverflow)?;
+
+    msg!("House vault withdrawal: {} lamports", amount);
+
+    Ok(())
+}
@@ -1,9 +1,12 @@
+
 use anchor_lang::prelude::*;
 
 // Import modules
 pub mod constants;
 pub mod errors;
+pub mod events;
 pub mod instructions;
+pub mod replay;
 pub mod state;
 
 // Import everything we need for the program
@@ -12,6 +15,7 @@ use instructions::*;
 // Re-export public types for external use
 pub use constants::*;
 pub use errors::*;
+pub use events::*;
 pub use state::*;
 
 declare_id!("CsCFNMvVnp8Mm1ijHJd7HvKHDB8TPQ9eKv2dptMpiXfK");
@@ -26,17 +30,366 @@ pub mod domin8_prgm {
     }
 
     /// Deposit a bet to join the current game round
-    pub fn deposit_bet(ctx: Context<DepositBet>, amount: u64) -> Result<()> {
-        instructions::deposit_bet(ctx, amount)
+    pub fn deposit_bet(ctx: Context<DepositBet>, amount: u64, join_code: Option<Vec<u8>>) -> Result<()> {
+        instructions::deposit_bet(ctx, amount, join_code)
+    }
+
+    /// Withdraw part or all of a stake before the round locks, less the configured penalty
+    pub fn withdraw_bet<'info>(ctx: Context<'_, '_, '_, 'info, WithdrawBet<'info>>, amount: u64) -> Result<()> {
+        instructions::withdraw_bet(ctx, amount)
     }
 
     /// UNIFIED INSTRUCTION: Progress game from Waiting directly to AwaitingWinnerRandomness with ORAO VRF
-    pub fn unified_progress_to_resolution(ctx: Context<UnifiedProgressToResolution>) -> Result<()> {
+    pub fn unified_progress_to_resolution<'info>(
+        ctx: Context<'_, '_, '_, 'info, UnifiedProgressToResolution<'info>>,
+    ) -> Result<()> {
         instructions::unified_progress_to_resolution(ctx)
     }
 
     /// UNIFIED INSTRUCTION: Resolve winner using ORAO VRF and immediately distribute winnings
-    pub fn unified_resolve_and_distribute(ctx: Context<UnifiedResolveAndDistribute>) -> Result<()> {
+    pub fn unified_resolve_and_distribute<'info>(
+        ctx: Context<'_, '_, '_, 'info, UnifiedResolveAndDistribute<'info>>,
+    ) -> Result<()> {
         instructions::unified_resolve_and_distribute(ctx)
     }
+
+    /// Close a round result past its retention period and return the rent to the authority
+    pub fn close_round_result(ctx: Context<CloseRoundResult>) -> Result<()> {
+        instructions::close_round_result(ctx)
+    }
+
+    /// Open a new room with its own bet limits, fee, duration and game mode
+    pub fn create_room(
+        ctx: Context<CreateRoom>,
+        room_id: u64,
+        min_bet: u64,
+        max_bet: u64,
+        house_fee_basis_points: u16,
+        waiting_phase_duration: u64,
+        game_mode: GameMode,
+    ) -> Result<()> {
+        instructions::create_room(ctx, room_id, min_bet, max_bet, house_fee_basis_points, waiting_phase_duration, game_mode)
+    }
+
+    /// Update a room's settings between rounds
+    pub fn update_room(
+        ctx: Context<UpdateRoom>,
+        min_bet: u64,
+        max_bet: u64,
+        house_fee_basis_points: u16,
+        waiting_phase_duration: u64,
+        game_mode: GameMode,
+    ) -> Result<()> {
+        instructions::update_room(ctx, min_bet, max_bet, house_fee_basis_points, waiting_phase_duration, game_mode)
+    }
+
+    /// Open a private room gated by an allowlist or a join code hash
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_private_room(
+        ctx: Context<CreatePrivateRoom>,
+        room_id: u64,
+        min_bet: u64,
+        max_bet: u64,
+        waiting_phase_duration: u64,
+        game_mode: GameMode,
+        access: RoomAccess,
+        join_code_hash: [u8; 32],
+        allowlist: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::create_private_room(
+            ctx, room_id, min_bet, max_bet, waiting_phase_duration, game_mode, access, join_code_hash, allowlist,
+        )
+    }
+
+    /// Replace a private room's allowlist or join code (room creator only)
+    pub fn set_room_access(
+        ctx: Context<SetRoomAccess>,
+        access: RoomAccess,
+        join_code_hash: [u8; 32],
+        allowlist: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_room_access(ctx, access, join_code_hash, allowlist)
+    }
+
+    /// Configure a room's top-N prize split (basis points per place, summing to 10,000)
+    pub fn set_prize_split(ctx: Context<SetPrizeSplit>, prize_split_basis_points: Vec<u16>) -> Result<()> {
+        instructions::set_prize_split(ctx, prize_split_basis_points)
+    }
+
+    /// Configure solo-vs-house rounds (odds and bankroll exposure limit)
+    pub fn set_solo_mode(
+        ctx: Context<SetSoloMode>,
+        enabled: bool,
+        win_probability_bps: u16,
+        house_match_bps: u16,
+        max_exposure_bps: u16,
+    ) -> Result<()> {
+        instructions::set_solo_mode(ctx, enabled, win_probability_bps, house_match_bps, max_exposure_bps)
+    }
+
+    /// Set the LP share of the house fee and the LP withdrawal cooldown
+    pub fn set_lp_pool_config(
+        ctx: Context<SetLpPoolConfig>,
+        fee_share_bps: u16,
+        withdrawal_cooldown: i64,
+    ) -> Result<()> {
+        instructions::set_lp_pool_config(ctx, fee_share_bps, withdrawal_cooldown)
+    }
+
+    /// Update the jackpot contribution rate and odds
+    pub fn set_jackpot_config(ctx: Context<SetJackpotConfig>, contribution_bps: u16, odds: u64) -> Result<()> {
+        instructions::set_jackpot_config(ctx, contribution_bps, odds)
+    }
+
+    /// Switch a room's bet currency between native SOL and an SPL token mint
+    pub fn set_bet_mint(ctx: Context<SetBetMint>, min_bet: u64) -> Result<()> {
+        instructions::set_bet_mint(ctx, min_bet)
+    }
+
+    /// Set the share of the treasury fee paid to private room creators
+    pub fn set_creator_fee_share(ctx: Context<SetCreatorFeeShare>, creator_fee_share_bps: u16) -> Result<()> {
+        instructions::set_creator_fee_share(ctx, creator_fee_share_bps)
+    }
+
+    /// Set the cap on the champion NFT holder bonus
+    pub fn set_nft_bonus_config(ctx: Context<SetNftBonusConfig>, max_nft_bonus_bps: u16) -> Result<()> {
+        instructions::set_nft_bonus_config(ctx, max_nft_bonus_bps)
+    }
+
+    /// Set the champion NFT base metadata URI and character roster size
+    pub fn set_champion_nft_config(
+        ctx: Context<SetChampionNftConfig>,
+        base_uri: String,
+        character_count: u16,
+    ) -> Result<()> {
+        instructions::set_champion_nft_config(ctx, base_uri, character_count)
+    }
+
+    /// Set the share of a referred player's house fee paid to their referrer
+    pub fn set_referral_fee_share(ctx: Context<SetReferralFeeShare>, referral_fee_share_bps: u16) -> Result<()> {
+        instructions::set_referral_fee_share(ctx, referral_fee_share_bps)
+    }
+
+    /// Update the share of the house fee that loyalty points redeem for
+    pub fn set_rakeback_config(ctx: Context<SetRakebackConfig>, rakeback_bps: u16) -> Result<()> {
+        instructions::set_rakeback_config(ctx, rakeback_bps)
+    }
+
+    /// Set how many Game Coins one SOL buys
+    pub fn set_coin_rate(ctx: Context<SetCoinRate>, coins_per_sol: u64) -> Result<()> {
+        instructions::set_coin_rate(ctx, coins_per_sol)
+    }
+
+    /// Set the relayer reimbursed for sponsored bets and its per-bet fee and cap
+    pub fn set_relayer_config(
+        ctx: Context<SetRelayerConfig>,
+        relayer: Pubkey,
+        relayer_fee_per_bet: u64,
+        max_relayer_fee_bps: u16,
+    ) -> Result<()> {
+        instructions::set_relayer_config(ctx, relayer, relayer_fee_per_bet, max_relayer_fee_bps)
+    }
+
+    /// Set the penalty kept from stakes withdrawn before the round locks
+    pub fn set_bet_withdrawal_penalty(ctx: Context<SetBetWithdrawalPenalty>, penalty_bps: u16) -> Result<()> {
+        instructions::set_bet_withdrawal_penalty(ctx, penalty_bps)
+    }
+
+    /// Set the minimum players per round and the waiting-window extensions before a refund
+    pub fn set_min_players(ctx: Context<SetMinPlayers>, min_players: u8, max_window_extensions: u8) -> Result<()> {
+        instructions::set_min_players(ctx, min_players, max_window_extensions)
+    }
+
+    /// Configure the soft close that pushes back betting close on late bets
+    pub fn set_late_bet_config(
+        ctx: Context<SetLateBetConfig>,
+        late_bet_window: i64,
+        late_bet_extension: i64,
+        max_late_bet_extension: i64,
+    ) -> Result<()> {
+        instructions::set_late_bet_config(ctx, late_bet_window, late_bet_extension, max_late_bet_extension)
+    }
+
+    /// Cap one wallet's share of the pot once the pot reaches a minimum size
+    pub fn set_wallet_cap(ctx: Context<SetWalletCap>, max_wallet_pot_share_bps: u16, wallet_cap_min_pot: u64) -> Result<()> {
+        instructions::set_wallet_cap(ctx, max_wallet_pot_share_bps, wallet_cap_min_pot)
+    }
+
+    /// Set the house fee tiers by pot size (up to 4, increasing thresholds)
+    pub fn set_fee_tiers(ctx: Context<SetFeeTiers>, fee_tiers: Vec<FeeTier>) -> Result<()> {
+        instructions::set_fee_tiers(ctx, fee_tiers)
+    }
+
+    /// Split the treasury's fee between several recipient wallets
+    pub fn set_fee_split(ctx: Context<SetFeeSplit>, fee_split: Vec<FeeRecipient>) -> Result<()> {
+        instructions::set_fee_split(ctx, fee_split)
+    }
+
+    /// Send a room's accrued house fees to the treasury or the fee split recipients
+    pub fn sweep_fees<'info>(ctx: Context<'_, '_, '_, 'info, SweepFees<'info>>) -> Result<()> {
+        instructions::sweep_fees(ctx)
+    }
+
+    /// Create the house bankroll vault
+    pub fn initialize_house_vault(ctx: Context<InitializeHouseVault>) -> Result<()> {
+        instructions::initialize_house_vault(ctx)
+    }
+
+    /// Fund the house bankroll from the authority
+    pub fn fund_house_vault(ctx: Context<FundHouseVault>, amount: u64) -> Result<()> {
+        instructions::fund_house_vault(ctx, amount)
+    }
+
+    /// Withdraw from the house bankroll to the authority
+    pub fn withdraw_house_vault(ctx: Context<WithdrawHouseVault>, amount: u64) -> Result<()> {
+        instructions::withdraw_house_vault(ctx, amount)
+    }
+
+    /// Create the SPL mint for house pool LP shares
+    pub fn initialize_lp_mint(ctx: Context<InitializeLpMint>) -> Result<()> {
+        instructions::initialize_lp_mint(ctx)
+    }
+
+    /// Deposit SOL into the house pool for LP shares
+    pub fn lp_deposit(ctx: Context<LpDeposit>, amount: u64) -> Result<()> {
+        instructions::lp_deposit(ctx, amount)
+    }
+
+    /// Burn LP shares and start the withdrawal cooldown
+    pub fn request_lp_withdrawal(ctx: Context<RequestLpWithdrawal>, shares: u64) -> Result<()> {
+        instructions::request_lp_withdrawal(ctx, shares)
+    }
+
+    /// Claim SOL for a matured LP withdrawal
+    pub fn complete_lp_withdrawal(ctx: Context<CompleteLpWithdrawal>) -> Result<()> {
+        instructions::complete_lp_withdrawal(ctx)
+    }
+
+    /// Create a tournament over a room's next rounds
+    pub fn create_tournament(
+        ctx: Context<CreateTournament>,
+        tournament_id: u64,
+        buy_in: u64,
+        total_rounds: u8,
+        prize_split_basis_points: Vec<u16>,
+    ) -> Result<()> {
+        instructions::create_tournament(ctx, tournament_id, buy_in, total_rounds, prize_split_basis_points)
+    }
+
+    /// Pay the buy-in and register for a tournament
+    pub fn join_tournament(ctx: Context<JoinTournament>) -> Result<()> {
+        instructions::join_tournament(ctx)
+    }
+
+    /// Pay a finished tournament's prize pool to the top standings
+    pub fn distribute_tournament_prizes(ctx: Context<DistributeTournamentPrizes>) -> Result<()> {
+        instructions::distribute_tournament_prizes(ctx)
+    }
+
+    /// Cancel a tournament still in registration and refund its entrants
+    pub fn cancel_tournament(ctx: Context<CancelTournament>) -> Result<()> {
+        instructions::cancel_tournament(ctx)
+    }
+
+    /// Create the "Royal Rumble Champions" collection NFT
+    pub fn initialize_champion_collection(ctx: Context<InitializeChampionCollection>, uri: String) -> Result<()> {
+        instructions::initialize_champion_collection(ctx, uri)
+    }
+
+    /// Mint the champion NFT for a won round (once per round, 0.01 SOL fee)
+    pub fn mint_champion_nft(ctx: Context<MintChampionNft>) -> Result<()> {
+        instructions::mint_champion_nft(ctx)
+    }
+
+    /// Create a season and direct a share of house fees to its prize pool
+    pub fn create_season(
+        ctx: Context<CreateSeason>,
+        season_id: u64,
+        start_ts: i64,
+        end_ts: i64,
+        fee_share_bps: u16,
+        points_formula: PointsFormula,
+        prize_split_basis_points: Vec<u16>,
+    ) -> Result<()> {
+        instructions::create_season(ctx, season_id, start_ts, end_ts, fee_share_bps, points_formula, prize_split_basis_points)
+    }
+
+    /// Enter a season's leaderboard
+    pub fn join_season(ctx: Context<JoinSeason>) -> Result<()> {
+        instructions::join_season(ctx)
+    }
+
+    /// Recompute a wallet's season points (permissionless)
+    pub fn update_season_score(ctx: Context<UpdateSeasonScore>) -> Result<()> {
+        instructions::update_season_score(ctx)
+    }
+
+    /// Pay an ended season's prize pool to the top wallets
+    pub fn finalize_season(ctx: Context<FinalizeSeason>) -> Result<()> {
+        instructions::finalize_season(ctx)
+    }
+
+    /// Register the wallet that referred this player (before their first bet)
+    pub fn register_referrer(ctx: Context<RegisterReferrer>, referrer: Pubkey) -> Result<()> {
+        instructions::register_referrer(ctx, referrer)
+    }
+
+    /// Withdraw accumulated referral earnings
+    pub fn claim_referral_earnings(ctx: Context<ClaimReferralEarnings>) -> Result<()> {
+        instructions::claim_referral_earnings(ctx)
+    }
+
+    /// Convert SOL into Game Coins held in the player's balance
+    pub fn deposit_funds(ctx: Context<DepositFunds>, coins: u64) -> Result<()> {
+        instructions::deposit_funds(ctx, coins)
+    }
+
+    /// Convert Game Coins back into SOL, less the 0.5% withdrawal fee
+    pub fn withdraw_funds(ctx: Context<WithdrawFunds>, coins: u64) -> Result<()> {
+        instructions::withdraw_funds(ctx, coins)
+    }
+
+    /// Bet Game Coins from the player's balance, signed by the player or a session key
+    pub fn deposit_bet_from_balance(
+        ctx: Context<DepositBetFromBalance>,
+        coins: u64,
+        join_code: Option<Vec<u8>>,
+    ) -> Result<()> {
+        instructions::deposit_bet_from_balance(ctx, coins, join_code)
+    }
+
+    /// Authorize an ephemeral key to bet from the player's balance up to a limit and expiry
+    pub fn create_session(
+        ctx: Context<CreateSession>,
+        session_key: Pubkey,
+        spending_limit: u64,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::create_session(ctx, session_key, spending_limit, expires_at)
+    }
+
+    /// Revoke a session key
+    pub fn revoke_session(ctx: Context<RevokeSession>) -> Result<()> {
+        instructions::revoke_session(ctx)
+    }
+
+    /// Create the rakeback reserve funded from the house fee
+    pub fn initialize_rakeback_reserve(ctx: Context<InitializeRakebackReserve>, rakeback_bps: u16) -> Result<()> {
+        instructions::initialize_rakeback_reserve(ctx, rakeback_bps)
+    }
+
+    /// Redeem loyalty points for rakeback from the reserve
+    pub fn claim_rakeback(ctx: Context<ClaimRakeback>) -> Result<()> {
+        instructions::claim_rakeback(ctx)
+    }
+
+    /// Create the progressive jackpot
+    pub fn initialize_jackpot(ctx: Context<InitializeJackpot>, contribution_bps: u16, odds: u64) -> Result<()> {
+        instructions::initialize_jackpot(ctx, contribution_bps, odds)
+    }
+
+    /// Create a room's vault token account for an SPL token bet mint
+    pub fn initialize_token_vault(ctx: Context<InitializeTokenVault>) -> Result<()> {
+        instructions::initialize_token_vault(ctx)
+    }
 }
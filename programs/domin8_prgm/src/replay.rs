@@ -0,0 +1,203 @@
+//! Deterministic battle replay derived from a round's VRF output
+//!
+//! Everything in here is pure: given a `RoundResult` account, clients and
+//! verifiers can recompute the exact elimination sequence the program commits to.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use crate::state::PlayerEntry;
+
+/// Domain separator so the replay seed can never collide with other hashes of the randomness
+pub const REPLAY_SEED_DOMAIN: &[u8] = b"domin8_replay";
+
+/// Domain separator for the champion character draw, independent of the replay draws
+pub const CHAMPION_CHARACTER_DOMAIN: &[u8] = b"domin8_champion";
+
+/// Derive the replay seed for a round from its fulfilled VRF randomness
+pub fn derive_replay_seed(round_id: u64, randomness: &[u8; 64]) -> [u8; 32] {
+    hashv(&[REPLAY_SEED_DOMAIN, &round_id.to_le_bytes(), randomness]).to_bytes()
+}
+
+/// Extract the u64 used for weighted winner selection from the fulfilled VRF randomness
+pub fn winner_randomness(randomness: &[u8; 64]) -> u64 {
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&randomness[0..8]);
+    u64::from_le_bytes(bytes)
+}
+
+/// Extract the u64 used for the jackpot draw, independent of the winner selection bytes
+pub fn jackpot_randomness(randomness: &[u8; 64]) -> u64 {
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&randomness[8..16]);
+    u64::from_le_bytes(bytes)
+}
+
+/// Final standings for a round: the winner first, then every other player
+/// drawn weighted by bet amount without replacement using the replay seed
+pub fn placement_order(replay_seed: &[u8; 32], players: &[PlayerEntry], winner: &Pubkey) -> Vec<Pubkey> {
+    let mut remaining: Vec<&PlayerEntry> = players.iter().filter(|p| p.wallet != *winner).collect();
+    let mut order = Vec::with_capacity(remaining.len() + 1);
+    order.push(*winner);
+
+    let mut draw: u32 = 0;
+    while !remaining.is_empty() {
+        let index = weighted_index(&remaining, replay_draw(replay_seed, draw));
+        order.push(remaining.remove(index).wallet);
+        draw += 1;
+    }
+
+    order
+}
+
+/// Order in which players are knocked out of the battle animation.
+/// The first entry is eliminated first and the last entry is the winner.
+pub fn elimination_order(replay_seed: &[u8; 32], players: &[PlayerEntry], winner: &Pubkey) -> Vec<Pubkey> {
+    let mut order = placement_order(replay_seed, players, winner);
+    order.reverse();
+    order
+}
+
+/// Character the round's champion is shown as, out of `character_count`
+pub fn champion_character(replay_seed: &[u8; 32], character_count: u16) -> u16 {
+    if character_count == 0 {
+        return 0;
+    }
+    let hash = hashv(&[CHAMPION_CHARACTER_DOMAIN, replay_seed]).to_bytes();
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&hash[0..8]);
+    (u64::from_le_bytes(bytes) % character_count as u64) as u16
+}
+
+/// Pseudo-random value for the n-th draw of a replay
+fn replay_draw(replay_seed: &[u8; 32], draw: u32) -> u64 {
+    let hash = hashv(&[replay_seed, &draw.to_le_bytes()]).to_bytes();
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&hash[0..8]);
+    u64::from_le_bytes(bytes)
+}
+
+/// Pick an index weighted by bet amounts (uniform if every remaining bet is zero)
+fn weighted_index(players: &[&PlayerEntry], value: u64) -> usize {
+    let total_weight: u64 = players.iter().fold(0u64, |acc, p| acc.saturating_add(p.total_bet));
+    if total_weight == 0 {
+        return (value % players.len() as u64) as usize;
+    }
+
+    let selection = value % total_weight;
+    let mut cumulative = 0u64;
+    for (index, player) in players.iter().enumerate() {
+        cumulative = cumulative.saturating_add(player.total_bet);
+        if selection < cumulative {
+            return index;
+        }
+    }
+
+    players.len() - 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn player(seed: u8, total_bet: u64) -> PlayerEntry {
+        PlayerEntry {
+            wallet: Pubkey::new_from_array([seed; 32]),
+            total_bet,
+            timestamp: 0,
+            balance_bet: 0,
+            sponsored_bets: 0,
+            loyalty_points: 0,
+        }
+    }
+
+    fn example_players() -> Vec<PlayerEntry> {
+        vec![player(1, 100), player(2, 300), player(3, 50), player(4, 550)]
+    }
+
+    #[test]
+    fn replay_seed_is_deterministic_and_bound_to_round() {
+        let randomness = [7u8; 64];
+
+        assert_eq!(derive_replay_seed(1, &randomness), derive_replay_seed(1, &randomness));
+        assert_ne!(derive_replay_seed(1, &randomness), derive_replay_seed(2, &randomness));
+        assert_ne!(derive_replay_seed(1, &randomness), derive_replay_seed(1, &[8u8; 64]));
+    }
+
+    #[test]
+    fn winner_and_jackpot_use_separate_bytes() {
+        let mut randomness = [0u8; 64];
+        randomness[0] = 1;
+        randomness[8] = 2;
+
+        assert_eq!(winner_randomness(&randomness), 1);
+        assert_eq!(jackpot_randomness(&randomness), 2);
+    }
+
+    #[test]
+    fn champion_character_is_deterministic_and_in_roster() {
+        let seed = derive_replay_seed(42, &[9u8; 64]);
+
+        for count in [1u16, 3, 12, u16::MAX] {
+            let character = champion_character(&seed, count);
+            assert!(character < count);
+            assert_eq!(character, champion_character(&seed, count));
+        }
+        assert_eq!(champion_character(&seed, 0), 0);
+    }
+
+    #[test]
+    fn placement_starts_with_winner_and_covers_every_player() {
+        let players = example_players();
+        let winner = players[2].wallet;
+        let seed = derive_replay_seed(42, &[9u8; 64]);
+
+        let order = placement_order(&seed, &players, &winner);
+
+        assert_eq!(order.len(), players.len());
+        assert_eq!(order[0], winner);
+        for entry in &players {
+            assert_eq!(order.iter().filter(|wallet| **wallet == entry.wallet).count(), 1);
+        }
+        assert_eq!(order, placement_order(&seed, &players, &winner));
+    }
+
+    #[test]
+    fn elimination_is_reverse_placement() {
+        let players = example_players();
+        let winner = players[0].wallet;
+        let seed = derive_replay_seed(3, &[5u8; 64]);
+
+        let mut placement = placement_order(&seed, &players, &winner);
+        let elimination = elimination_order(&seed, &players, &winner);
+
+        assert_eq!(*elimination.last().unwrap(), winner);
+        placement.reverse();
+        assert_eq!(elimination, placement);
+    }
+
+    #[test]
+    fn weighted_index_follows_cumulative_bets() {
+        let players = example_players();
+        let refs: Vec<&PlayerEntry> = players.iter().collect();
+
+        assert_eq!(weighted_index(&refs, 0), 0);
+        assert_eq!(weighted_index(&refs, 99), 0);
+        assert_eq!(weighted_index(&refs, 100), 1);
+        assert_eq!(weighted_index(&refs, 399), 1);
+        assert_eq!(weighted_index(&refs, 400), 2);
+        assert_eq!(weighted_index(&refs, 450), 3);
+        assert_eq!(weighted_index(&refs, 999), 3);
+        // Values wrap around the total weight
+        assert_eq!(weighted_index(&refs, 1_000), 0);
+    }
+
+    #[test]
+    fn weighted_index_is_uniform_without_weight() {
+        let players = [player(1, 0), player(2, 0), player(3, 0)];
+        let refs: Vec<&PlayerEntry> = players.iter().collect();
+
+        assert_eq!(weighted_index(&refs, 0), 0);
+        assert_eq!(weighted_index(&refs, 4), 1);
+        assert_eq!(weighted_index(&refs, 5), 2);
+    }
+}
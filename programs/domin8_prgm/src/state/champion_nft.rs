@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use crate::state::Season;
+
+/// On-chain attributes of a "Royal Rumble Champions" NFT, copied from the
+/// won round's `RoundResult` when it is minted. Artwork and character
+/// appearance live in the Token Metadata URI built from the config base URI.
+/// Seeds: [b"champion_nft", champion_mint]
+/// Mint: [b"champion_mint", round_result] (one NFT per won round)
+#[account]
+pub struct ChampionNft {
+    pub mint: Pubkey,
+    pub room_id: u64,
+    pub round_id: u64,
+    pub winner: Pubkey,
+    pub character_id: u16,      // Drawn from the round's replay seed
+
+    // Win statistics, in the round's bet mint units
+    pub bet_mint: Pubkey,       // Pubkey::default() = lamports
+    pub player_count: u8,
+    pub winner_bet: u64,
+    pub total_pot: u64,
+    pub winnings: u64,          // Winner payout plus any jackpot
+    pub won_at: i64,
+    pub minted_at: i64,
+
+    pub counted_season: Pubkey, // Season whose score includes this NFT (Pubkey::default() = none)
+}
+
+impl ChampionNft {
+    /// 8 (discriminator) + 32 (mint) + 8 (room_id) + 8 (round_id) + 32 (winner) + 2 (character_id)
+    /// + 32 (bet_mint) + 1 (player_count) + 8 (winner_bet) + 8 (total_pot) + 8 (winnings)
+    /// + 8 (won_at) + 8 (minted_at) + 32 (counted_season) = 194 bytes
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 32 + 2 + 32 + 1 + 8 + 8 + 8 + 8 + 8 + 32;
+
+    /// Whether this NFT still has to be added to `player`'s score in `season`:
+    /// won by them during the season and not counted for it before
+    pub fn counts_for_season(&self, season_key: &Pubkey, season: &Season, player: &Pubkey) -> bool {
+        self.winner == *player
+            && self.won_at >= season.start_ts
+            && self.won_at <= season.end_ts
+            && self.counted_season != *season_key
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::PointsFormula;
+
+    fn season(start_ts: i64, end_ts: i64) -> Season {
+        Season {
+            season_id: 1,
+            start_ts,
+            end_ts,
+            fee_share_bps: 0,
+            points_formula: PointsFormula::default(),
+            prize_split_basis_points: [10_000, 0, 0, 0, 0],
+            prize_pool: 0,
+            finalized: false,
+            leaderboard: Vec::new(),
+        }
+    }
+
+    fn champion_nft(winner: Pubkey, won_at: i64) -> ChampionNft {
+        ChampionNft {
+            mint: Pubkey::new_unique(),
+            room_id: 0,
+            round_id: 1,
+            winner,
+            character_id: 0,
+            bet_mint: Pubkey::default(),
+            player_count: 2,
+            winner_bet: 0,
+            total_pot: 0,
+            winnings: 0,
+            won_at,
+            minted_at: won_at,
+            counted_season: Pubkey::default(),
+        }
+    }
+
+    #[test]
+    fn nft_counts_once_per_season() {
+        let season_key = Pubkey::new_unique();
+        let player = Pubkey::new_unique();
+        let mut nft = champion_nft(player, 150);
+
+        assert!(nft.counts_for_season(&season_key, &season(100, 200), &player));
+        nft.counted_season = season_key;
+        assert!(!nft.counts_for_season(&season_key, &season(100, 200), &player));
+    }
+
+    #[test]
+    fn nft_won_outside_season_or_by_another_wallet_does_not_count() {
+        let season_key = Pubkey::new_unique();
+        let player = Pubkey::new_unique();
+
+        assert!(!champion_nft(player, 99).counts_for_season(&season_key, &season(100, 200), &player));
+        assert!(!champion_nft(player, 201).counts_for_season(&season_key, &season(100, 200), &player));
+        assert!(!champion_nft(Pubkey::new_unique(), 150).counts_for_season(&season_key, &season(100, 200), &player));
+    }
+}
@@ -1,4 +1,9 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL;
+use crate::constants::{
+    BASIS_POINTS_DENOMINATOR, HOUSE_FEE_BASIS_POINTS, MAX_CHAMPION_BASE_URI_LENGTH, MAX_FEE_RECIPIENTS, MAX_FEE_TIERS,
+    NFT_BONUS_BPS_PER_NFT,
+};
 
 /// Configuration for game durations
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -10,43 +15,521 @@ impl GameDurationConfig {
     pub const LEN: usize = 8 + 8 + 8 + 8; // 32 bytes
 }
 
-/// Global game configuration stored as singleton PDA
+/// House fee for pots below `pot_below`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct FeeTier {
+    pub pot_below: u64,
+    pub fee_bps: u16,
+}
+
+impl FeeTier {
+    /// Fee tier size: 8 (pot_below) + 2 (fee_bps) = 10 bytes
+    pub const LEN: usize = 8 + 2;
+
+    /// Tiers must have strictly increasing thresholds and fees below 100%
+    pub fn is_valid_table(tiers: &[FeeTier]) -> bool {
+        tiers.len() <= MAX_FEE_TIERS
+            && tiers.iter().all(|tier| tier.fee_bps < BASIS_POINTS_DENOMINATOR)
+            && tiers.windows(2).all(|pair| pair[0].pot_below < pair[1].pot_below)
+    }
+}
+
+/// One recipient of the treasury's fee and its share
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct FeeRecipient {
+    pub wallet: Pubkey,
+    pub share_bps: u16,
+}
+
+impl FeeRecipient {
+    /// Fee recipient size: 32 (wallet) + 2 (share_bps) = 34 bytes
+    pub const LEN: usize = 32 + 2;
+
+    /// A split needs distinct recipients with non-zero shares summing to 10,000 bps
+    pub fn is_valid_split(recipients: &[FeeRecipient]) -> bool {
+        let total: u32 = recipients.iter().map(|r| r.share_bps as u32).sum();
+        let distinct = recipients
+            .iter()
+            .enumerate()
+            .all(|(i, r)| recipients[..i].iter().all(|other| other.wallet != r.wallet));
+        !recipients.is_empty()
+            && recipients.len() <= MAX_FEE_RECIPIENTS
+            && recipients.iter().all(|r| r.share_bps > 0)
+            && total == BASIS_POINTS_DENOMINATOR as u32
+            && distinct
+    }
+}
+
+/// Global game configuration stored as singleton PDA.
+/// Per-room economics (bets, fee, duration, mode, mint) live on `Room`.
 /// Seeds: [b"game_config"]
 #[account]
 pub struct GameConfig {
     pub authority: Pubkey,
     pub treasury: Pubkey,
-    pub house_fee_basis_points: u16,     // 500 = 5%
-    pub min_bet_lamports: u64,           // 10,000,000 = 0.01 SOL
-    pub small_game_duration_config: GameDurationConfig,
     
     // ORAO VRF configuration
     pub vrf_fee_lamports: u64,        // Fee for VRF requests (0.001 SOL)
     pub vrf_network_state: Pubkey,    // ORAO network state account
     pub vrf_treasury: Pubkey,         // ORAO treasury account
+    
+    // Solo-vs-house mode, backed by the house vault
+    pub solo_mode_enabled: bool,
+    pub solo_win_probability_bps: u16,   // Solo player's chance to win the matched pot
+    pub solo_house_match_bps: u16,       // House stake relative to player stake (10_000 = 1:1)
+    pub house_max_exposure_bps: u16,     // Max share of the bankroll matched in one round
+    
+    // Liquidity-provider pool
+    pub lp_fee_share_bps: u16,           // Share of the house fee paid into the house vault for LPs
+    pub lp_withdrawal_cooldown: i64,     // Seconds between an LP withdrawal request and payout
+    
+    // Private rooms
+    pub creator_fee_share_bps: u16,      // Share of the treasury's cut paid to a private room's creator
+    
+    // Champion NFT metadata
+    pub champion_base_uri: String,       // Metadata URIs are "{base}/{room_id}/{round_id}.json"
+    pub champion_character_count: u16,   // Characters a champion is drawn from (0 = minting disabled)
+    
+    // Champion NFT holder bonus
+    pub max_nft_bonus_bps: u16,          // Cap on the winner's bonus from held champion NFTs
+    
+    // Seasons
+    pub current_season: Pubkey,          // Season funded at resolution (Pubkey::default() = none)
+    
+    // Referrals
+    pub referral_fee_share_bps: u16,     // Share of a referred player's house fee paid to the referrer
+    
+    // Game Coins
+    pub coins_per_sol: u64,              // Game Coins credited per SOL deposited into a player balance
+    
+    // Sponsored bets
+    pub relayer: Pubkey,                 // Fee payer reimbursed for sponsored bets (Pubkey::default() = none)
+    pub relayer_fee_per_bet: u64,        // Reimbursement per sponsored bet, in lamports
+    pub max_relayer_fee_bps: u16,        // Cap on a round's reimbursement as a share of the house fee
+    
+    // Bet withdrawals
+    pub bet_withdrawal_penalty_bps: u16, // Share of a stake withdrawn before lock kept as a house fee
+    
+    // Minimum participants
+    pub min_players: u8,                 // Players needed to lock a round (a lone solo-mode player plays the house)
+    pub max_window_extensions: u8,       // Waiting windows added to an under-filled round before it is refunded
+    
+    // Soft close
+    pub late_bet_window: i64,            // Bets in the final N seconds before close push the close back
+    pub late_bet_extension: i64,         // Seconds each late bet adds
+    pub max_late_bet_extension: i64,     // Cap on the seconds late bets can add to one round
+    
+    // Wallet wager cap
+    pub max_wallet_pot_share_bps: u16,   // Largest share of the pot one wallet may hold (0 = no cap)
+    pub wallet_cap_min_pot: u64,         // Pot size from which the cap applies
+    
+    // House fee tiers by pot size (empty = each room's flat fee)
+    pub fee_tiers: Vec<FeeTier>,
+    
+    // Treasury fee split (empty = all to `treasury`)
+    pub fee_split: Vec<FeeRecipient>,
 }
 
 impl GameConfig {
     /// Account space calculation:
-    /// 8 (discriminator) + 32 (authority) + 32 (treasury) + 2 (house_fee) + 8 (min_bet) 
-    /// + 32 (small_game_config) + 32 (large_game_config) 
-    /// + 8 (vrf_fee) + 32 (vrf_network_state) + 32 (vrf_treasury) = 218 bytes
-    pub const LEN: usize = 8 + 32 + 32 + 2 + 8 + GameDurationConfig::LEN + GameDurationConfig::LEN + 8 + 32 + 32;
+    /// 8 (discriminator) + 32 (authority) + 32 (treasury)
+    /// + 8 (vrf_fee) + 32 (vrf_network_state) + 32 (vrf_treasury)
+    /// + 1 (solo_mode_enabled) + 2 (solo_win_probability) + 2 (solo_house_match) + 2 (house_max_exposure)
+    /// + 2 (lp_fee_share) + 8 (lp_withdrawal_cooldown)
+    /// + 2 (creator_fee_share) + 4 + 150 (champion_base_uri) + 2 (champion_character_count)
+    /// + 2 (max_nft_bonus) + 32 (current_season)
+    /// + 2 (referral_fee_share) + 8 (coins_per_sol)
+    /// + 32 (relayer) + 8 (relayer_fee_per_bet) + 2 (max_relayer_fee_bps)
+    /// + 2 (bet_withdrawal_penalty) + 1 (min_players) + 1 (max_window_extensions)
+    /// + 8 (late_bet_window) + 8 (late_bet_extension) + 8 (max_late_bet_extension)
+    /// + 2 (max_wallet_pot_share) + 8 (wallet_cap_min_pot)
+    /// + 4 + (4 * 10) (fee_tiers) + 4 + (5 * 34) (fee_split) = 661 bytes
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 32 + 32
+        + 1 + 2 + 2 + 2
+        + 2 + 8
+        + 2 + 4 + MAX_CHAMPION_BASE_URI_LENGTH + 2
+        + 2 + 32
+        + 2 + 8
+        + 32 + 8 + 2
+        + 2 + 1 + 1
+        + 8 + 8 + 8
+        + 2 + 8
+        + 4 + (MAX_FEE_TIERS * FeeTier::LEN)
+        + 4 + (MAX_FEE_RECIPIENTS * FeeRecipient::LEN);
 
-    /// Calculate house fee from pot amount
+    /// House fee for a pot: the first tier whose threshold the pot is below,
+    /// the last tier above every threshold, or the default flat fee without tiers
     pub fn calculate_house_fee(&self, pot_amount: u64) -> u64 {
-        pot_amount
-            .saturating_mul(self.house_fee_basis_points as u64)
-            .saturating_div(10_000)
+        let fee_bps = self.fee_tiers
+            .iter()
+            .find(|tier| pot_amount < tier.pot_below)
+            .or(self.fee_tiers.last())
+            .map_or(HOUSE_FEE_BASIS_POINTS, |tier| tier.fee_bps);
+        ((pot_amount as u128 * fee_bps as u128) / BASIS_POINTS_DENOMINATOR as u128) as u64
+    }
+
+    /// House stake required to match a solo player's bet
+    pub fn calculate_house_match(&self, stake: u64) -> u64 {
+        ((stake as u128 * self.solo_house_match_bps as u128) / BASIS_POINTS_DENOMINATOR as u128)
+            .min(u64::MAX as u128) as u64
+    }
+
+    /// Largest house match allowed against the given available bankroll
+    pub fn max_house_exposure(&self, available_bankroll: u64) -> u64 {
+        ((available_bankroll as u128 * self.house_max_exposure_bps as u128) / BASIS_POINTS_DENOMINATOR as u128) as u64
     }
 
-    /// Calculate winner payout after house fee
-    pub fn calculate_winner_payout(&self, pot_amount: u64) -> u64 {
-        pot_amount.saturating_sub(self.calculate_house_fee(pot_amount))
+    /// Portion of the house fee credited to LPs through the house vault
+    pub fn calculate_lp_fee(&self, house_fee: u64) -> u64 {
+        ((house_fee as u128 * self.lp_fee_share_bps as u128) / BASIS_POINTS_DENOMINATOR as u128) as u64
     }
 
-    /// Validate if a bet amount meets minimum requirements
-    pub fn is_valid_bet_amount(&self, amount: u64) -> bool {
-        amount >= self.min_bet_lamports
+    /// Winner bonus for holding `nft_count` champion NFTs, at 0.1% of the payout per NFT up to the cap
+    pub fn calculate_nft_bonus(&self, winner_payout: u64, nft_count: usize) -> u64 {
+        let bonus_bps = (nft_count as u128 * NFT_BONUS_BPS_PER_NFT as u128).min(self.max_nft_bonus_bps as u128);
+        ((winner_payout as u128 * bonus_bps) / BASIS_POINTS_DENOMINATOR as u128) as u64
+    }
+
+    /// Referral fee for a player whose bets produced `player_house_fee`
+    pub fn calculate_referral_fee(&self, player_house_fee: u64) -> u64 {
+        ((player_house_fee as u128 * self.referral_fee_share_bps as u128) / BASIS_POINTS_DENOMINATOR as u128) as u64
+    }
+
+    /// Lamports backing `coins` Game Coins at the current rate
+    pub fn coins_to_lamports(&self, coins: u64) -> Option<u64> {
+        let lamports = (coins as u128 * LAMPORTS_PER_SOL as u128).checked_div(self.coins_per_sol as u128)?;
+        u64::try_from(lamports).ok()
+    }
+
+    /// Relayer reimbursement for a round's sponsored bets, capped at a share of its house fee
+    pub fn calculate_relayer_fee(&self, sponsored_bets: u32, house_fee: u64) -> u64 {
+        let cap = ((house_fee as u128 * self.max_relayer_fee_bps as u128) / BASIS_POINTS_DENOMINATOR as u128) as u64;
+        self.relayer_fee_per_bet.saturating_mul(sponsored_bets as u64).min(cap)
+    }
+
+    /// Penalty kept from a stake withdrawn before the round locks
+    pub fn calculate_withdrawal_penalty(&self, amount: u64) -> u64 {
+        ((amount as u128 * self.bet_withdrawal_penalty_bps as u128) / BASIS_POINTS_DENOMINATOR as u128) as u64
+    }
+
+    /// Largest top-up that keeps a wallet holding `wallet_bet` of `pot` within the
+    /// pot share cap, counting the top-up in both (u64::MAX when uncapped)
+    pub fn max_wallet_top_up(&self, wallet_bet: u64, pot: u64) -> u64 {
+        let share = self.max_wallet_pot_share_bps as u128;
+        let denominator = BASIS_POINTS_DENOMINATOR as u128;
+        if share == 0 || share >= denominator {
+            return u64::MAX;
+        }
+        
+        // (wallet_bet + x) / (pot + x) <= share  =>  x <= (pot * share - wallet_bet) / (1 - share)
+        let headroom = (pot as u128 * share).saturating_sub(wallet_bet as u128 * denominator);
+        (headroom / (denominator - share)).min(u64::MAX as u128) as u64
+    }
+
+    /// Largest bet a wallet holding `wallet_bet` of `pot` may add (u64::MAX when uncapped).
+    /// A wallet betting alone holds the whole pot by definition, so until a rival
+    /// joins its bets are limited to `wallet_cap_min_pot` in total instead.
+    pub fn max_wallet_bet(&self, wallet_bet: u64, pot: u64, has_rival: bool) -> u64 {
+        if self.max_wallet_pot_share_bps == 0 {
+            return u64::MAX;
+        }
+        if !has_rival {
+            return self.wallet_cap_min_pot.saturating_sub(wallet_bet);
+        }
+        if pot < self.wallet_cap_min_pot {
+            return u64::MAX;
+        }
+        self.max_wallet_top_up(wallet_bet, pot)
+    }
+
+    /// Split the treasury's fee by the configured shares; the last recipient
+    /// takes the rounding remainder so the parts always sum to `treasury_fee`
+    pub fn split_treasury_fee(&self, treasury_fee: u64) -> Vec<(Pubkey, u64)> {
+        let mut remaining = treasury_fee;
+        self.fee_split
+            .iter()
+            .enumerate()
+            .map(|(i, recipient)| {
+                let amount = if i + 1 == self.fee_split.len() {
+                    remaining
+                } else {
+                    ((treasury_fee as u128 * recipient.share_bps as u128) / BASIS_POINTS_DENOMINATOR as u128) as u64
+                };
+                remaining = remaining.saturating_sub(amount);
+                (recipient.wallet, amount)
+            })
+            .collect()
+    }
+
+    /// Metadata URI of a round's champion NFT
+    pub fn champion_nft_uri(&self, room_id: u64, round_id: u64) -> String {
+        format!("{}/{}/{}.json", self.champion_base_uri.trim_end_matches('/'), room_id, round_id)
+    }
+
+    /// Portion of the treasury's fee paid to a private room's creator
+    pub fn calculate_creator_fee(&self, treasury_fee: u64) -> u64 {
+        ((treasury_fee as u128 * self.creator_fee_share_bps as u128) / BASIS_POINTS_DENOMINATOR as u128) as u64
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL;
+
+    fn config_with_tiers(fee_tiers: Vec<FeeTier>) -> GameConfig {
+        GameConfig {
+            authority: Pubkey::default(),
+            treasury: Pubkey::default(),
+            vrf_fee_lamports: 0,
+            vrf_network_state: Pubkey::default(),
+            vrf_treasury: Pubkey::default(),
+            solo_mode_enabled: false,
+            solo_win_probability_bps: 0,
+            solo_house_match_bps: 0,
+            house_max_exposure_bps: 0,
+            lp_fee_share_bps: 0,
+            lp_withdrawal_cooldown: 0,
+            creator_fee_share_bps: 0,
+            champion_base_uri: String::new(),
+            champion_character_count: 0,
+            max_nft_bonus_bps: 0,
+            current_season: Pubkey::default(),
+            referral_fee_share_bps: 0,
+            coins_per_sol: 0,
+            relayer: Pubkey::default(),
+            relayer_fee_per_bet: 0,
+            max_relayer_fee_bps: 0,
+            bet_withdrawal_penalty_bps: 0,
+            min_players: 0,
+            max_window_extensions: 0,
+            late_bet_window: 0,
+            late_bet_extension: 0,
+            max_late_bet_extension: 0,
+            max_wallet_pot_share_bps: 0,
+            wallet_cap_min_pot: 0,
+            fee_tiers,
+            fee_split: Vec::new(),
+        }
+    }
+
+    /// 5% below 1 SOL, 4% below 10 SOL, 3% above
+    fn example_tiers() -> Vec<FeeTier> {
+        vec![
+            FeeTier { pot_below: LAMPORTS_PER_SOL, fee_bps: 500 },
+            FeeTier { pot_below: 10 * LAMPORTS_PER_SOL, fee_bps: 400 },
+            FeeTier { pot_below: u64::MAX, fee_bps: 300 },
+        ]
+    }
+
+    #[test]
+    fn tier_boundaries() {
+        let config = config_with_tiers(example_tiers());
+
+        assert_eq!(config.calculate_house_fee(LAMPORTS_PER_SOL - 1), (LAMPORTS_PER_SOL - 1) * 5 / 100);
+        assert_eq!(config.calculate_house_fee(LAMPORTS_PER_SOL), LAMPORTS_PER_SOL * 4 / 100);
+        assert_eq!(config.calculate_house_fee(10 * LAMPORTS_PER_SOL - 1), (10 * LAMPORTS_PER_SOL - 1) * 4 / 100);
+        assert_eq!(config.calculate_house_fee(10 * LAMPORTS_PER_SOL), 10 * LAMPORTS_PER_SOL * 3 / 100);
+        assert_eq!(config.calculate_house_fee(0), 0);
+    }
+
+    #[test]
+    fn last_tier_applies_above_every_threshold() {
+        let config = config_with_tiers(vec![
+            FeeTier { pot_below: LAMPORTS_PER_SOL, fee_bps: 500 },
+            FeeTier { pot_below: 10 * LAMPORTS_PER_SOL, fee_bps: 300 },
+        ]);
+
+        assert_eq!(config.calculate_house_fee(100 * LAMPORTS_PER_SOL), 3 * LAMPORTS_PER_SOL);
+    }
+
+    #[test]
+    fn empty_table_uses_default_fee() {
+        let config = config_with_tiers(Vec::new());
+
+        assert_eq!(config.calculate_house_fee(LAMPORTS_PER_SOL), LAMPORTS_PER_SOL * 5 / 100);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn fee_does_not_overflow_at_u64_max() {
+        let config = config_with_tiers(example_tiers());
+        let expected = ((u64::MAX as u128 * 300) / BASIS_POINTS_DENOMINATOR as u128) as u64;
+
+        assert_eq!(config.calculate_house_fee(u64::MAX), expected);
+
+        let config = config_with_tiers(vec![FeeTier { pot_below: u64::MAX, fee_bps: BASIS_POINTS_DENOMINATOR - 1 }]);
+        assert!(config.calculate_house_fee(u64::MAX) < u64::MAX);
+    }
+
+    #[test]
+    fn tier_table_validation() {
+        assert!(FeeTier::is_valid_table(&example_tiers()));
+        assert!(FeeTier::is_valid_table(&[]));
+
+        let unordered = vec![
+            FeeTier { pot_below: 10 * LAMPORTS_PER_SOL, fee_bps: 400 },
+            FeeTier { pot_below: LAMPORTS_PER_SOL, fee_bps: 500 },
+        ];
+        assert!(!FeeTier::is_valid_table(&unordered));
+
+        let full_fee = vec![FeeTier { pot_below: u64::MAX, fee_bps: BASIS_POINTS_DENOMINATOR }];
+        assert!(!FeeTier::is_valid_table(&full_fee));
+
+        let too_many = vec![FeeTier { pot_below: 1, fee_bps: 0 }; MAX_FEE_TIERS + 1];
+        assert!(!FeeTier::is_valid_table(&too_many));
+    }
+
+    fn config_with_wallet_cap(max_wallet_pot_share_bps: u16) -> GameConfig {
+        let mut config = config_with_tiers(Vec::new());
+        config.max_wallet_pot_share_bps = max_wallet_pot_share_bps;
+        config
+    }
+
+    #[test]
+    fn wallet_top_up_is_unbounded_without_cap() {
+        let config = config_with_wallet_cap(0);
+
+        assert_eq!(config.max_wallet_top_up(0, 0), u64::MAX);
+        assert_eq!(config.max_wallet_top_up(LAMPORTS_PER_SOL, LAMPORTS_PER_SOL), u64::MAX);
+    }
+
+    #[test]
+    fn wallet_top_up_keeps_share_within_cap() {
+        // 50% cap: a new wallet can match the rest of a 1 SOL pot
+        let config = config_with_wallet_cap(5_000);
+        assert_eq!(config.max_wallet_top_up(0, LAMPORTS_PER_SOL), LAMPORTS_PER_SOL);
+
+        // 25% cap, wallet holds 1 of a 10 SOL pot: (10 * 0.25 - 1) / 0.75 = 2 SOL
+        let config = config_with_wallet_cap(2_500);
+        assert_eq!(config.max_wallet_top_up(LAMPORTS_PER_SOL, 10 * LAMPORTS_PER_SOL), 2 * LAMPORTS_PER_SOL);
+    }
+
+    #[test]
+    fn wallet_top_up_is_zero_at_or_above_cap() {
+        let config = config_with_wallet_cap(2_500);
+
+        assert_eq!(config.max_wallet_top_up(0, 0), 0);
+        assert_eq!(config.max_wallet_top_up(LAMPORTS_PER_SOL, 4 * LAMPORTS_PER_SOL), 0);
+        assert_eq!(config.max_wallet_top_up(2 * LAMPORTS_PER_SOL, 4 * LAMPORTS_PER_SOL), 0);
+    }
+
+    #[test]
+    fn wallet_top_up_does_not_overflow() {
+        let config = config_with_wallet_cap(BASIS_POINTS_DENOMINATOR - 1);
+
+        assert_eq!(config.max_wallet_top_up(0, u64::MAX), u64::MAX);
+    }
+
+    #[test]
+    fn first_bettor_is_capped_at_the_minimum_pot() {
+        let mut config = config_with_wallet_cap(2_500);
+        config.wallet_cap_min_pot = 5 * LAMPORTS_PER_SOL;
+
+        assert_eq!(config.max_wallet_bet(0, 0, false), 5 * LAMPORTS_PER_SOL);
+        assert_eq!(config.max_wallet_bet(2 * LAMPORTS_PER_SOL, 2 * LAMPORTS_PER_SOL, false), 3 * LAMPORTS_PER_SOL);
+        assert_eq!(config.max_wallet_bet(5 * LAMPORTS_PER_SOL, 5 * LAMPORTS_PER_SOL, false), 0);
+    }
+
+    #[test]
+    fn wallet_bet_with_a_rival_is_capped_from_the_minimum_pot() {
+        let mut config = config_with_wallet_cap(2_500);
+        config.wallet_cap_min_pot = 5 * LAMPORTS_PER_SOL;
+
+        assert_eq!(config.max_wallet_bet(0, 4 * LAMPORTS_PER_SOL, true), u64::MAX);
+        assert_eq!(config.max_wallet_bet(LAMPORTS_PER_SOL, 10 * LAMPORTS_PER_SOL, true), 2 * LAMPORTS_PER_SOL);
+
+        config.max_wallet_pot_share_bps = 0;
+        assert_eq!(config.max_wallet_bet(0, 0, false), u64::MAX);
+    }
+
+    #[test]
+    fn withdrawal_penalty_is_a_share_of_the_withdrawn_stake() {
+        let mut config = config_with_tiers(Vec::new());
+        assert_eq!(config.calculate_withdrawal_penalty(LAMPORTS_PER_SOL), 0);
+
+        config.bet_withdrawal_penalty_bps = 250;
+        assert_eq!(config.calculate_withdrawal_penalty(LAMPORTS_PER_SOL), LAMPORTS_PER_SOL / 40);
+        assert_eq!(config.calculate_withdrawal_penalty(39), 0); // Rounds down in the player's favour
+    }
+
+    #[test]
+    fn withdrawal_penalty_does_not_overflow_at_the_maximum_rate() {
+        let mut config = config_with_tiers(Vec::new());
+        config.bet_withdrawal_penalty_bps = crate::constants::MAX_BET_WITHDRAWAL_PENALTY_BPS;
+
+        assert_eq!(config.calculate_withdrawal_penalty(u64::MAX), u64::MAX / 5);
+    }
+
+    #[test]
+    fn champion_uri_is_built_from_base_and_round() {
+        let mut config = config_with_tiers(Vec::new());
+        config.champion_base_uri = "https://nft.domin8.gg/champions/".to_string();
+
+        assert_eq!(config.champion_nft_uri(0, 17), "https://nft.domin8.gg/champions/0/17.json");
+    }
+
+    #[test]
+    fn longest_champion_uri_fits_token_metadata() {
+        let mut config = config_with_tiers(Vec::new());
+        config.champion_base_uri = "x".repeat(MAX_CHAMPION_BASE_URI_LENGTH);
+
+        assert!(config.champion_nft_uri(u64::MAX, u64::MAX).len() <= crate::constants::MAX_NFT_URI_LENGTH);
+    }
+
+    fn recipient(share_bps: u16) -> FeeRecipient {
+        FeeRecipient { wallet: Pubkey::new_unique(), share_bps }
+    }
+
+    #[test]
+    fn fee_split_must_cover_the_whole_fee_once() {
+        assert!(FeeRecipient::is_valid_split(&[recipient(7_000), recipient(3_000)]));
+
+        assert!(!FeeRecipient::is_valid_split(&[]));
+        assert!(!FeeRecipient::is_valid_split(&[recipient(7_000), recipient(2_999)]));
+        assert!(!FeeRecipient::is_valid_split(&[recipient(10_000), recipient(0)]));
+
+        let duplicate = recipient(5_000);
+        assert!(!FeeRecipient::is_valid_split(&[duplicate.clone(), duplicate]));
+
+        let too_many: Vec<_> = (0..MAX_FEE_RECIPIENTS + 1).map(|_| recipient(1)).collect();
+        assert!(!FeeRecipient::is_valid_split(&too_many));
+    }
+
+    #[test]
+    fn last_fee_recipient_takes_the_rounding_remainder() {
+        let mut config = config_with_tiers(Vec::new());
+        config.fee_split = vec![recipient(3_333), recipient(3_333), recipient(3_334)];
+
+        let amounts: Vec<u64> = config.split_treasury_fee(100).into_iter().map(|(_, amount)| amount).collect();
+        assert_eq!(amounts, vec![33, 33, 34]);
+        assert!(config.split_treasury_fee(0).iter().all(|(_, amount)| *amount == 0));
+    }
+
+    #[test]
+    fn coins_convert_at_the_configured_rate() {
+        let mut config = config_with_tiers(Vec::new());
+        config.coins_per_sol = 1_000;
+
+        assert_eq!(config.coins_to_lamports(1), Some(LAMPORTS_PER_SOL / 1_000));
+        assert_eq!(config.coins_to_lamports(2_500), Some(5 * LAMPORTS_PER_SOL / 2));
+    }
+
+    #[test]
+    fn coins_do_not_convert_without_a_rate_or_past_u64() {
+        let mut config = config_with_tiers(Vec::new());
+        assert_eq!(config.coins_to_lamports(1), None);
+
+        config.coins_per_sol = 1;
+        assert_eq!(config.coins_to_lamports(u64::MAX), None);
+    }
+
+    #[test]
+    fn relayer_fee_is_per_sponsored_bet_up_to_a_share_of_the_house_fee() {
+        let mut config = config_with_tiers(Vec::new());
+        config.relayer_fee_per_bet = 5_000;
+        config.max_relayer_fee_bps = 1_000;
+
+        assert_eq!(config.calculate_relayer_fee(3, 1_000_000), 15_000);
+        assert_eq!(config.calculate_relayer_fee(3, 100_000), 10_000);
+        assert_eq!(config.calculate_relayer_fee(0, 1_000_000), 0);
+    }
+}
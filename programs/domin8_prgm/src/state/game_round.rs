@@ -15,10 +15,11 @@ impl GameStatus {
     pub const LEN: usize = 1; // Enum is 1 byte
 }
 
-/// Current game round state stored as singleton PDA
-/// Seeds: [b"game_round"]
+/// Current game round state, one per room
+/// Seeds: [b"game_round", room_id.to_le_bytes()]
 #[account]
 pub struct GameRound {
+    pub room_id: u64,
     pub round_id: u64,
     pub status: GameStatus,
     pub start_timestamp: i64,
@@ -29,6 +30,8 @@ pub struct GameRound {
     
     // Pot tracking
     pub initial_pot: u64,
+    pub house_stake: u64,              // House match in solo-vs-house rounds (0 otherwise)
+    pub solo_win_probability_bps: u16, // Solo odds snapshotted when the house matched
     // pub spectator_pot: u64,  // Removed for small games MVP
     
     // Winner
@@ -38,16 +41,34 @@ pub struct GameRound {
     pub vrf_request_pubkey: Pubkey,    // ORAO VRF request account
     pub vrf_seed: [u8; 32],           // Seed used for VRF request
     pub randomness_fulfilled: bool,    // Track if randomness is ready
+    
+    // Tournament this round counts towards (Pubkey::default() = none)
+    pub tournament: Pubkey,
+    
+    // Bets placed through the configured relayer, reimbursed at resolution
+    pub sponsored_bets: u32,
+    
+    // Waiting windows added because the round had too few players
+    pub window_extensions: u8,
+    
+    // Betting closes at `close_at`; late bets push it back by up to the configured cap
+    pub close_at: i64,
+    pub late_bet_extension: i64,       // Seconds added by late bets so far
+    
+    // House fees (lamports) kept in the room vault until `sweep_fees`
+    pub accrued_fees: u64,
 }
 
 impl GameRound {
     /// Account space calculation for small games MVP with ORAO VRF:
-    /// 8 (discriminator) + 8 (round_id) + 1 (status) + 8 (start_timestamp)
-    /// + 4 (players vec len) + (64 * 48) (max players)
-    /// + 8 (initial_pot) + 32 (winner) 
-    /// + 32 (vrf_request_pubkey) + 32 (vrf_seed) + 1 (randomness_fulfilled)
-    /// = 8 + 8 + 1 + 8 + 4 + 3072 + 8 + 32 + 32 + 32 + 1 = 3206 bytes (~3.1KB)
-    pub const LEN: usize = 8 + 8 + GameStatus::LEN + 8 + 4 + (64 * PlayerEntry::LEN) + 8 + 32 + 32 + 32 + 1;
+    /// 8 (discriminator) + 8 (room_id) + 8 (round_id) + 1 (status) + 8 (start_timestamp)
+    /// + 4 (players vec len) + (64 * 66) (max players)
+    /// + 8 (initial_pot) + 8 (house_stake) + 2 (solo_win_probability_bps) + 32 (winner) 
+    /// + 32 (vrf_request_pubkey) + 32 (vrf_seed) + 1 (randomness_fulfilled) + 32 (tournament)
+    /// + 4 (sponsored_bets) + 1 (window_extensions) + 8 (close_at) + 8 (late_bet_extension)
+    /// + 8 (accrued_fees)
+    ///   = 8 + 8 + 8 + 1 + 8 + 4 + 4224 + 8 + 8 + 2 + 32 + 32 + 32 + 1 + 32 + 4 + 1 + 8 + 8 + 8 = 4437 bytes (~4.3KB)
+    pub const LEN: usize = 8 + 8 + 8 + GameStatus::LEN + 8 + 4 + (64 * PlayerEntry::LEN) + 8 + 8 + 2 + 32 + 32 + 32 + 1 + 32 + 4 + 1 + 8 + 8 + 8;
 
     /// Check if the game is in a state where players can join
     pub fn can_accept_players(&self) -> bool {
@@ -69,8 +90,104 @@ impl GameRound {
         self.players.iter_mut().find(|p| p.wallet == *wallet)
     }
 
-    /// Calculate total pot value (just initial pot in small games MVP)
+    /// Check if this round is a solo player matched by the house vault
+    pub fn is_solo(&self) -> bool {
+        self.house_stake > 0
+    }
+
+    /// Check if this round counts towards a tournament
+    pub fn is_tournament_round(&self) -> bool {
+        self.tournament != Pubkey::default()
+    }
+
+    /// Check if bets are still open at `now`
+    pub fn is_betting_open(&self, now: i64) -> bool {
+        self.status == GameStatus::Idle || (self.status == GameStatus::Waiting && now < self.close_at)
+    }
+
+    /// Soft close: a bet inside the final `late_bet_window` seconds pushes the
+    /// close back by `extension` seconds, until `max_extension` seconds have been added
+    pub fn apply_late_bet(&mut self, now: i64, late_bet_window: i64, extension: i64, max_extension: i64) {
+        if late_bet_window <= 0 || now < self.close_at.saturating_sub(late_bet_window) {
+            return;
+        }
+        let added = extension.min(max_extension.saturating_sub(self.late_bet_extension)).max(0);
+        self.close_at = self.close_at.saturating_add(added);
+        self.late_bet_extension = self.late_bet_extension.saturating_add(added);
+    }
+
+    /// Give an under-filled round another waiting window from `now`. Returns
+    /// false once `max_extensions` windows have been added and the round must be refunded.
+    pub fn extend_waiting_window(&mut self, now: i64, waiting_duration: i64, max_extensions: u8) -> bool {
+        if self.window_extensions >= max_extensions {
+            return false;
+        }
+        self.window_extensions += 1;
+        self.close_at = now.saturating_add(waiting_duration);
+        true
+    }
+
+    /// Return the round to Idle for its next first player, keeping the round id
+    pub fn reset_waiting(&mut self) {
+        self.status = GameStatus::Idle;
+        self.players.clear();
+        self.start_timestamp = 0;
+        self.initial_pot = 0;
+        self.sponsored_bets = 0;
+        self.window_extensions = 0;
+        self.close_at = 0;
+        self.late_bet_extension = 0;
+    }
+
+    /// Calculate total pot value (player bets plus any house match)
     pub fn total_pot(&self) -> u64 {
-        self.initial_pot
+        self.initial_pot.saturating_add(self.house_stake)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn waiting_round(close_at: i64) -> GameRound {
+        let mut round = GameRound::deserialize(&mut &[0u8; GameRound::LEN][..]).unwrap();
+        round.status = GameStatus::Waiting;
+        round.close_at = close_at;
+        round
+    }
+
+    #[test]
+    fn late_bet_pushes_the_close_back_up_to_the_cap() {
+        let mut round = waiting_round(1_000);
+
+        round.apply_late_bet(990, 15, 10, 25);
+        assert_eq!(round.close_at, 1_010);
+        round.apply_late_bet(1_005, 15, 10, 25);
+        assert_eq!(round.close_at, 1_020);
+        round.apply_late_bet(1_015, 15, 10, 25);
+        assert_eq!((round.close_at, round.late_bet_extension), (1_025, 25));
+
+        round.apply_late_bet(1_024, 15, 10, 25);
+        assert_eq!(round.close_at, 1_025);
+    }
+
+    #[test]
+    fn early_bet_or_disabled_window_leaves_the_close_alone() {
+        let mut round = waiting_round(1_000);
+
+        round.apply_late_bet(984, 15, 10, 25);
+        round.apply_late_bet(999, 0, 10, 25);
+        assert_eq!((round.close_at, round.late_bet_extension), (1_000, 0));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn waiting_window_extends_until_the_limit() {
+        let mut round = waiting_round(1_000);
+
+        assert!(round.extend_waiting_window(1_000, 60, 2));
+        assert_eq!(round.close_at, 1_060);
+        assert!(round.extend_waiting_window(1_060, 60, 2));
+        assert!(!round.extend_waiting_window(1_120, 60, 2));
+        assert_eq!((round.close_at, round.window_extensions), (1_120, 2));
+    }
+}
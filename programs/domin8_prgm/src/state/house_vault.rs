@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+
+/// House bankroll backing solo-vs-house rounds. The bankroll itself is the
+/// account's lamport balance above rent exemption, less stakes matched in
+/// unsettled solo rounds and LP withdrawals waiting out their cooldown.
+/// LP shares are minted against that bankroll.
+/// Seeds: [b"house_vault"]
+#[account]
+pub struct HouseVault {
+    pub total_funded: u64,       // Lifetime lamports funded by the authority
+    pub total_withdrawn: u64,    // Lifetime lamports withdrawn by the authority
+    pub total_matched: u64,      // Lifetime lamports staked against solo players
+    pub total_won: u64,          // Lifetime lamports returned from solo rounds the house won
+    pub reserved_stake: u64,     // House matches in solo rounds awaiting resolution, across all rooms
+
+    // Liquidity-provider pool
+    pub total_shares: u64,        // Outstanding LP shares (mirrors the LP mint supply)
+    pub pending_withdrawals: u64, // Lamports owed to LPs in cooldown, excluded from the bankroll
+    pub total_lp_fees: u64,       // Lifetime house fees credited to LPs
+}
+
+impl HouseVault {
+    /// 8 (discriminator) + 8 (total_funded) + 8 (total_withdrawn) + 8 (total_matched) + 8 (total_won)
+    /// + 8 (reserved_stake) + 8 (total_shares) + 8 (pending_withdrawals) + 8 (total_lp_fees) = 72 bytes
+    pub const LEN: usize = 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8;
+
+    /// Lamports available to the bankroll, keeping the account rent exempt and
+    /// excluding stakes in play plus lamports already promised to withdrawing LPs
+    pub fn available_bankroll(&self, vault_info: &AccountInfo) -> Result<u64> {
+        let rent_exempt_minimum = Rent::get()?.minimum_balance(vault_info.data_len());
+        Ok(vault_info
+            .lamports()
+            .saturating_sub(rent_exempt_minimum)
+            .saturating_sub(self.reserved_stake)
+            .saturating_sub(self.pending_withdrawals))
+    }
+
+    /// Bankroll that LP shares are priced against: stakes in play still count at par,
+    /// so deposits and withdrawals made while a solo round is open neither dilute nor drain LPs
+    pub fn share_bankroll(&self, vault_info: &AccountInfo) -> Result<u64> {
+        Ok(self.available_bankroll(vault_info)?.saturating_add(self.reserved_stake))
+    }
+
+    /// Whether the bankroll holds capital no LP share has a claim on, i.e. lamports
+    /// the authority funded before any shares existed
+    pub fn has_unshared_capital(&self, bankroll: u64) -> bool {
+        self.total_shares == 0 && bankroll > 0
+    }
+
+    /// Shares minted for a deposit, priced against the bankroll before the deposit.
+    /// The first deposit mints 1:1 only into an empty bankroll, otherwise it would
+    /// take ownership of the authority's capital; an LP pool wiped down to zero
+    /// cannot price new shares either.
+    pub fn shares_for_deposit(&self, amount: u64, bankroll: u64) -> Option<u64> {
+        if self.total_shares == 0 {
+            return (bankroll == 0).then_some(amount);
+        }
+        if bankroll == 0 {
+            return None;
+        }
+        u64::try_from((amount as u128).checked_mul(self.total_shares as u128)? / bankroll as u128).ok()
+    }
+
+    /// Lamports redeemable for a number of shares at the current bankroll
+    pub fn lamports_for_shares(&self, shares: u64, bankroll: u64) -> Option<u64> {
+        if self.total_shares == 0 {
+            return None;
+        }
+        u64::try_from((shares as u128).checked_mul(bankroll as u128)? / self.total_shares as u128).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vault_with_shares(total_shares: u64) -> HouseVault {
+        HouseVault {
+            total_funded: 0,
+            total_withdrawn: 0,
+            total_matched: 0,
+            total_won: 0,
+            reserved_stake: 0,
+            total_shares,
+            pending_withdrawals: 0,
+            total_lp_fees: 0,
+        }
+    }
+
+    #[test]
+    fn first_deposit_into_empty_bankroll_is_one_to_one() {
+        let vault = vault_with_shares(0);
+
+        assert!(!vault.has_unshared_capital(0));
+        assert_eq!(vault.shares_for_deposit(1_000, 0), Some(1_000));
+    }
+
+    #[test]
+    fn first_deposit_cannot_claim_authority_capital() {
+        let vault = vault_with_shares(0);
+
+        assert!(vault.has_unshared_capital(5_000));
+        assert_eq!(vault.shares_for_deposit(1_000, 5_000), None);
+        assert_eq!(vault.lamports_for_shares(1_000, 5_000), None);
+    }
+
+    #[test]
+    fn deposits_are_priced_against_the_bankroll() {
+        // 1_000 shares backed by 2_000 lamports: each share is worth 2 lamports
+        let vault = vault_with_shares(1_000);
+
+        assert!(!vault.has_unshared_capital(2_000));
+        assert_eq!(vault.shares_for_deposit(500, 2_000), Some(250));
+        assert_eq!(vault.lamports_for_shares(250, 2_000), Some(500));
+        assert_eq!(vault.lamports_for_shares(1_000, 2_000), Some(2_000));
+    }
+
+    #[test]
+    fn deposits_into_a_drained_pool_are_rejected() {
+        let vault = vault_with_shares(1_000);
+
+        assert_eq!(vault.shares_for_deposit(500, 0), None);
+        assert_eq!(vault.lamports_for_shares(1_000, 0), Some(0));
+    }
+
+    #[test]
+    fn share_math_does_not_overflow() {
+        let vault = vault_with_shares(u64::MAX);
+
+        assert_eq!(vault.shares_for_deposit(u64::MAX, u64::MAX), Some(u64::MAX));
+        assert_eq!(vault.shares_for_deposit(u64::MAX, 1), None);
+        assert_eq!(vault.lamports_for_shares(u64::MAX, u64::MAX), Some(u64::MAX));
+    }
+}
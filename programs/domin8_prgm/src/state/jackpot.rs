@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use crate::constants::BASIS_POINTS_DENOMINATOR;
+
+/// Progressive jackpot funded by a slice of every pot. The prize is the
+/// account's lamports above rent exemption; `balance` mirrors it so the
+/// frontend can show the counter without a rent calculation.
+/// Seeds: [b"jackpot"]
+#[account]
+pub struct Jackpot {
+    pub balance: u64,             // Current prize in lamports
+    pub contribution_bps: u16,    // Slice of every pot added to the jackpot
+    pub odds: u64,                // 1-in-`odds` chance per round that the winner also takes the jackpot
+    pub total_contributed: u64,
+    pub total_paid: u64,
+
+    // Last hit
+    pub last_winner: Pubkey,
+    pub last_payout: u64,
+    pub last_won_round: u64,
+}
+
+impl Jackpot {
+    /// 8 (discriminator) + 8 (balance) + 2 (contribution_bps) + 8 (odds)
+    /// + 8 (total_contributed) + 8 (total_paid)
+    /// + 32 (last_winner) + 8 (last_payout) + 8 (last_won_round) = 90 bytes
+    pub const LEN: usize = 8 + 8 + 2 + 8 + 8 + 8 + 32 + 8 + 8;
+
+    /// Jackpot contribution taken from a pot at resolution
+    pub fn calculate_contribution(&self, pot_amount: u64) -> u64 {
+        ((pot_amount as u128 * self.contribution_bps as u128) / BASIS_POINTS_DENOMINATOR as u128) as u64
+    }
+
+    /// Check whether the round's jackpot randomness hits
+    pub fn is_hit(&self, randomness: u64) -> bool {
+        randomness.checked_rem(self.odds) == Some(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn jackpot(contribution_bps: u16, odds: u64) -> Jackpot {
+        let mut jackpot = Jackpot::deserialize(&mut &[0u8; Jackpot::LEN][..]).unwrap();
+        jackpot.contribution_bps = contribution_bps;
+        jackpot.odds = odds;
+        jackpot
+    }
+
+    #[test]
+    fn contribution_is_a_slice_of_the_pot() {
+        assert_eq!(jackpot(100, 1_000).calculate_contribution(10_000), 100);
+        assert_eq!(jackpot(100, 1_000).calculate_contribution(99), 0);
+        assert_eq!(jackpot(crate::constants::MAX_JACKPOT_CONTRIBUTION_BPS, 1_000).calculate_contribution(u64::MAX), u64::MAX / 10);
+    }
+
+    #[test]
+    fn jackpot_hits_one_in_odds() {
+        let jackpot = jackpot(100, 1_000);
+
+        assert!(jackpot.is_hit(0));
+        assert!(jackpot.is_hit(3_000));
+        assert!(!jackpot.is_hit(2_999));
+    }
+
+    #[test]
+    fn jackpot_never_hits_with_zero_odds() {
+        assert!(!jackpot(100, 0).is_hit(0));
+    }
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+/// Pending LP withdrawal. Shares are burned and the payout fixed when the
+/// request is made; lamports are released once the cooldown has passed.
+/// Seeds: [b"lp_withdrawal", owner]
+#[account]
+pub struct LpWithdrawal {
+    pub owner: Pubkey,
+    pub shares: u64,        // Shares burned for this withdrawal
+    pub lamports: u64,      // Lamports owed to the owner
+    pub requested_at: i64,
+    pub unlock_at: i64,
+}
+
+impl LpWithdrawal {
+    /// 8 (discriminator) + 32 (owner) + 8 (shares) + 8 (lamports) + 8 (requested_at) + 8 (unlock_at) = 72 bytes
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 8 + 8;
+}
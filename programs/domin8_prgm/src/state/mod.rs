@@ -1,8 +1,36 @@
 // State module - contains all account definitions
+pub mod champion_nft;
 pub mod game_config;
 pub mod game_round;
+pub mod house_vault;
+pub mod jackpot;
+pub mod lp_withdrawal;
+pub mod player_balance;
 pub mod player_entry;
+pub mod player_stats;
+pub mod rakeback_reserve;
+pub mod referral;
+pub mod room;
+pub mod round_result;
+pub mod season;
+pub mod season_stats;
+pub mod session_token;
+pub mod tournament;
 
+pub use champion_nft::*;
 pub use game_config::*;
 pub use game_round::*;
-pub use player_entry::*;
\ No newline at end of file
+pub use house_vault::*;
+pub use jackpot::*;
+pub use lp_withdrawal::*;
+pub use player_balance::*;
+pub use player_entry::*;
+pub use player_stats::*;
+pub use rakeback_reserve::*;
+pub use referral::*;
+pub use room::*;
+pub use round_result::*;
+pub use season::*;
+pub use season_stats::*;
+pub use session_token::*;
+pub use tournament::*;
\ No newline at end of file
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::constants::{BASIS_POINTS_DENOMINATOR, COIN_WITHDRAWAL_FEE_BPS, MIN_COIN_WITHDRAWAL_FEE};
+
+/// A player's internal Game Coins balance. The ledger is kept in lamports,
+/// held by the account above rent, so changing the coin rate never leaves a
+/// balance unbacked; coin amounts are converted at the rate in `GameConfig`.
+/// Seeds: [b"player_balance", wallet]
+#[account]
+pub struct PlayerBalance {
+    pub player: Pubkey,
+    pub balance: u64,            // Lamports available to bet or withdraw
+    pub total_deposited: u64,
+    pub total_withdrawn: u64,    // Before withdrawal fees
+}
+
+impl PlayerBalance {
+    /// 8 (discriminator) + 32 (player) + 8 (balance)
+    /// + 8 (total_deposited) + 8 (total_withdrawn) = 64 bytes
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 8;
+
+    /// Treasury fee on a withdrawal: 0.5% with a 0.001 SOL minimum
+    pub fn calculate_withdrawal_fee(lamports: u64) -> u64 {
+        let fee = ((lamports as u128 * COIN_WITHDRAWAL_FEE_BPS as u128) / BASIS_POINTS_DENOMINATOR as u128) as u64;
+        fee.max(MIN_COIN_WITHDRAWAL_FEE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL;
+
+    #[test]
+    fn withdrawal_fee_is_a_share_with_a_minimum() {
+        assert_eq!(PlayerBalance::calculate_withdrawal_fee(10 * LAMPORTS_PER_SOL), LAMPORTS_PER_SOL / 20);
+        assert_eq!(PlayerBalance::calculate_withdrawal_fee(LAMPORTS_PER_SOL / 10), MIN_COIN_WITHDRAWAL_FEE);
+        assert_eq!(PlayerBalance::calculate_withdrawal_fee(u64::MAX), u64::MAX / 200);
+    }
+}
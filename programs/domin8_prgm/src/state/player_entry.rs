@@ -6,9 +6,13 @@ pub struct PlayerEntry {
     pub wallet: Pubkey,
     pub total_bet: u64,
     pub timestamp: i64,
+    pub balance_bet: u64,    // Part of total_bet paid from the player's Game Coins balance
+    pub sponsored_bets: u16, // Bets this player placed through the relayer
+    pub loyalty_points: u64, // Accrued by this stake, taken back if it is withdrawn or refunded
 }
 
 impl PlayerEntry {
-    /// Player entry size: 32 (wallet) + 8 (total_bet) + 8 (timestamp) = 48 bytes
-    pub const LEN: usize = 32 + 8 + 8;
+    /// Player entry size: 32 (wallet) + 8 (total_bet) + 8 (timestamp) + 8 (balance_bet)
+    /// + 2 (sponsored_bets) + 8 (loyalty_points) = 66 bytes
+    pub const LEN: usize = 32 + 8 + 8 + 8 + 2 + 8;
 }
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+/// Lifetime statistics for one wallet, kept on-chain so leaderboards can be verified.
+/// Created by the player's first `deposit_bet` and settled by `unified_resolve_and_distribute`.
+/// Lamport totals only count native SOL rooms; rounds in token rooms still count.
+/// Seeds: [b"player_stats", wallet]
+#[account]
+pub struct PlayerStats {
+    pub player: Pubkey,
+    pub rounds_played: u64,
+    pub rounds_won: u64,
+    pub total_wagered: u64,
+    pub total_won: u64,          // Prize payouts plus jackpots
+    pub largest_win: u64,
+    pub current_streak: u32,     // Consecutive rounds won
+    pub best_streak: u32,
+    pub last_settled: Pubkey,    // RoundResult of the last round applied, so no round counts twice
+
+    // Rakeback
+    pub loyalty_points: u64,     // Unredeemed house fee on the player's SOL bets, accrued when each bet is placed
+    pub rakeback_claimed: u64,
+
+    pub first_bet_at: i64,       // Set by the wallet's first bet and never cleared, even if that bet is withdrawn
+}
+
+impl PlayerStats {
+    /// 8 (discriminator) + 32 (player) + 8 (rounds_played) + 8 (rounds_won)
+    /// + 8 (total_wagered) + 8 (total_won) + 8 (largest_win)
+    /// + 4 (current_streak) + 4 (best_streak) + 32 (last_settled)
+    /// + 8 (loyalty_points) + 8 (rakeback_claimed) + 8 (first_bet_at) = 144 bytes
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 8 + 8 + 8 + 4 + 4 + 32 + 8 + 8 + 8;
+
+    /// Stats stored at a wallet's PDA, or None while the wallet has never bet.
+    /// Callers pass the PDA unconditionally so an existing account cannot be left out.
+    pub fn load_if_initialized(info: &AccountInfo) -> Result<Option<Self>> {
+        if info.data_is_empty() {
+            return Ok(None);
+        }
+        require_keys_eq!(*info.owner, crate::ID, anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram);
+        Ok(Some(Self::try_deserialize(&mut &info.data.borrow()[..])?))
+    }
+
+    /// Whether the wallet has ever placed a bet
+    pub fn has_bet(&self) -> bool {
+        self.first_bet_at != 0
+    }
+
+    /// Apply a settled round: `won` is the round's winner flag, `payout` what the player received
+    pub fn record_result(&mut self, round_result: Pubkey, won: bool, payout: u64) {
+        self.last_settled = round_result;
+        self.total_won = self.total_won.saturating_add(payout);
+        self.largest_win = self.largest_win.max(payout);
+
+        if won {
+            self.rounds_won = self.rounds_won.saturating_add(1);
+            self.current_streak = self.current_streak.saturating_add(1);
+            self.best_streak = self.best_streak.max(self.current_streak);
+        } else {
+            self.current_streak = 0;
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::constants::BASIS_POINTS_DENOMINATOR;
+
+/// Pool that pays out rakeback. Funded with `rakeback_bps` of the house fee
+/// charged on every SOL round's player stakes, the same fee loyalty points
+/// accrue from; the claimable pool is the account's lamports above rent exemption.
+/// Seeds: [b"rakeback_reserve"]
+#[account]
+pub struct RakebackReserve {
+    pub rakeback_bps: u16,        // Share of the house fee a player generated that their loyalty points redeem for
+    pub total_funded: u64,
+    pub total_claimed: u64,
+}
+
+impl RakebackReserve {
+    /// 8 (discriminator) + 2 (rakeback_bps) + 8 (total_funded) + 8 (total_claimed) = 26 bytes
+    pub const LEN: usize = 8 + 2 + 8 + 8;
+
+    /// Rakeback owed for `amount` of house fee (or loyalty points, which are counted in house fee lamports)
+    pub fn calculate_rakeback(&self, amount: u64) -> u64 {
+        ((amount as u128 * self.rakeback_bps as u128) / BASIS_POINTS_DENOMINATOR as u128) as u64
+    }
+
+    /// Loyalty points whose rakeback fits within `available` lamports, capped at `points`
+    pub fn redeemable_points(&self, points: u64, available: u64) -> u64 {
+        if self.rakeback_bps == 0 {
+            return 0;
+        }
+        let affordable = (available as u128 * BASIS_POINTS_DENOMINATOR as u128) / self.rakeback_bps as u128;
+        affordable.min(points as u128) as u64
+    }
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+/// A player's referrer, registered once before their first bet
+/// Seeds: [b"referral", player]
+#[account]
+pub struct Referral {
+    pub player: Pubkey,
+    pub referrer: Pubkey,
+    pub registered_at: i64,
+}
+
+impl Referral {
+    /// 8 (discriminator) + 32 (player) + 32 (referrer) + 8 (registered_at) = 80 bytes
+    pub const LEN: usize = 8 + 32 + 32 + 8;
+}
+
+/// A referrer's earnings. Unclaimed earnings are held as the account's
+/// lamports above rent until `claim_referral_earnings`.
+/// Seeds: [b"referrer", referrer]
+#[account]
+pub struct ReferrerAccount {
+    pub referrer: Pubkey,
+    pub registrations: u64,      // Referral registrations naming this referrer
+    pub claimable: u64,
+    pub total_earned: u64,
+    pub total_claimed: u64,
+}
+
+impl ReferrerAccount {
+    /// 8 (discriminator) + 32 (referrer) + 8 (registrations) + 8 (claimable)
+    /// + 8 (total_earned) + 8 (total_claimed) = 72 bytes
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 8 + 8;
+}
@@ -0,0 +1,241 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use crate::constants::{BASIS_POINTS_DENOMINATOR, JOIN_CODE_DOMAIN, MAX_PRIZE_PLACES, MAX_ROOM_ALLOWLIST};
+use crate::state::{GameConfig, GameDurationConfig};
+
+/// How a room pays out its pot
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub enum GameMode {
+    WinnerTakesAll,   // Single weighted winner takes the pot
+    PrizeSplit,       // Top-N payout using the room's prize split table
+}
+
+impl GameMode {
+    pub const LEN: usize = 1; // Enum is 1 byte
+}
+
+/// Who may bet in a room
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub enum RoomAccess {
+    Public,      // Anyone
+    Allowlist,   // Only wallets on the room's allowlist
+    JoinCode,    // Anyone who supplies the preimage of the room's join code hash
+}
+
+impl RoomAccess {
+    pub const LEN: usize = 1; // Enum is 1 byte
+}
+
+/// Independent game room with its own economics, round and vault
+/// Seeds: [b"room", room_id.to_le_bytes()]
+/// Round: [b"game_round", room_id.to_le_bytes()], vault: [b"vault", room_id.to_le_bytes()]
+#[account]
+pub struct Room {
+    pub room_id: u64,
+    
+    // Bet limits, in the bet mint's base units (lamports for native SOL)
+    pub min_bet: u64,
+    pub max_bet: u64,                    // Per deposit, 0 = no maximum
+    
+    pub house_fee_basis_points: u16,     // 500 = 5%
+    pub duration_config: GameDurationConfig,
+    pub game_mode: GameMode,
+    
+    // Top-N prize split for PrizeSplit rooms, zero-terminated
+    pub prize_split_basis_points: [u16; MAX_PRIZE_PLACES],
+    
+    // Bet currency (Pubkey::default() = native SOL)
+    pub bet_mint: Pubkey,                // SPL token / Token-2022 mint for token rooms
+    pub bet_mint_decimals: u8,
+    
+    // Private rooms (public rooms have the authority as creator)
+    pub creator: Pubkey,                 // Receives the creator share of the house fee in private rooms
+    pub access: RoomAccess,
+    pub join_code_hash: [u8; 32],        // Room::hash_join_code(room_id, code) for JoinCode rooms
+    pub allowlist: Vec<Pubkey>,          // Up to MAX_ROOM_ALLOWLIST wallets for Allowlist rooms
+}
+
+impl Room {
+    /// Account space calculation:
+    /// 8 (discriminator) + 8 (room_id) + 8 (min_bet) + 8 (max_bet) + 2 (house_fee)
+    /// + 32 (duration_config) + 1 (game_mode) + (5 * 2) (prize_split_basis_points)
+    /// + 32 (bet_mint) + 1 (bet_mint_decimals)
+    /// + 32 (creator) + 1 (access) + 32 (join_code_hash) + 4 + (32 * 32) (allowlist) = 1203 bytes
+    pub const LEN: usize = 8 + 8 + 8 + 8 + 2 + GameDurationConfig::LEN + GameMode::LEN
+        + (MAX_PRIZE_PLACES * 2)
+        + 32 + 1
+        + 32 + RoomAccess::LEN + 32 + 4 + (MAX_ROOM_ALLOWLIST * 32);
+
+    /// House fee charged on a pot: the room's flat fee, lowered by the config's
+    /// pot-size tiers in native SOL rooms (tiers are in lamports, and a room's fee
+    /// is never raised). Every fee, payout and loyalty calculation goes through here.
+    pub fn calculate_house_fee(&self, config: &GameConfig, pot_amount: u64) -> u64 {
+        let flat_fee = ((pot_amount as u128 * self.house_fee_basis_points as u128) / BASIS_POINTS_DENOMINATOR as u128) as u64;
+        if self.is_token_pool() || config.fee_tiers.is_empty() {
+            return flat_fee;
+        }
+        flat_fee.min(config.calculate_house_fee(pot_amount))
+    }
+
+    /// House fee on one stake at the rate charged on the pot it is part of
+    pub fn calculate_stake_house_fee(&self, config: &GameConfig, stake: u64, pot_amount: u64) -> u64 {
+        if pot_amount == 0 {
+            return 0;
+        }
+        ((self.calculate_house_fee(config, pot_amount) as u128 * stake as u128) / pot_amount as u128) as u64
+    }
+
+    /// Calculate winner payout after house fee
+    pub fn calculate_winner_payout(&self, config: &GameConfig, pot_amount: u64) -> u64 {
+        pot_amount.saturating_sub(self.calculate_house_fee(config, pot_amount))
+    }
+
+    /// Number of paid places (always 1 unless the room splits prizes)
+    pub fn prize_places(&self) -> usize {
+        match self.game_mode {
+            GameMode::WinnerTakesAll => 1,
+            GameMode::PrizeSplit => self.prize_split_basis_points.iter().take_while(|bps| **bps > 0).count().max(1),
+        }
+    }
+
+    /// Split the pot remaining after the house fee across `placed_count` wallets.
+    /// Shares for places nobody filled, plus rounding dust, go to the winner (index 0).
+    pub fn calculate_prize_payouts(&self, distributable: u64, placed_count: usize) -> Vec<u64> {
+        let mut payouts = vec![0u64; placed_count.min(self.prize_places()).max(1)];
+        let mut paid = 0u64;
+        for (place, payout) in payouts.iter_mut().enumerate().skip(1) {
+            // bps <= 10_000 so the share never exceeds `distributable`
+            *payout = ((distributable as u128 * self.prize_split_basis_points[place] as u128)
+                / BASIS_POINTS_DENOMINATOR as u128) as u64;
+            paid = paid.saturating_add(*payout);
+        }
+        payouts[0] = distributable.saturating_sub(paid);
+        payouts
+    }
+
+    /// Validate a prize split table: 1..=MAX_PRIZE_PLACES non-zero entries summing to 100%
+    pub fn is_valid_prize_split(split: &[u16]) -> bool {
+        !split.is_empty()
+            && split.len() <= MAX_PRIZE_PLACES
+            && split.iter().all(|bps| *bps > 0)
+            && split.iter().map(|bps| *bps as u32).sum::<u32>() == BASIS_POINTS_DENOMINATOR as u32
+    }
+
+    /// Check if bets are made in an SPL token instead of native SOL
+    pub fn is_token_pool(&self) -> bool {
+        self.bet_mint != Pubkey::default()
+    }
+
+    /// Private rooms restrict who may bet and share the house fee with their creator
+    pub fn is_private(&self) -> bool {
+        self.access != RoomAccess::Public
+    }
+
+    /// Hash of a join code, salted with the room id so equal codes differ across rooms
+    pub fn hash_join_code(room_id: u64, join_code: &[u8]) -> [u8; 32] {
+        hashv(&[JOIN_CODE_DOMAIN, &room_id.to_le_bytes(), join_code]).to_bytes()
+    }
+
+    /// Check whether a wallet may bet in this room
+    pub fn can_join(&self, wallet: &Pubkey, join_code: Option<&[u8]>) -> bool {
+        match self.access {
+            RoomAccess::Public => true,
+            RoomAccess::Allowlist => self.allowlist.contains(wallet),
+            RoomAccess::JoinCode => join_code
+                .is_some_and(|code| Room::hash_join_code(self.room_id, code) == self.join_code_hash),
+        }
+    }
+
+    /// Validate private room access settings
+    pub fn is_valid_access(access: RoomAccess, join_code_hash: &[u8; 32], allowlist: &[Pubkey]) -> bool {
+        match access {
+            RoomAccess::Public => false, // Private rooms cannot be opened to everyone
+            RoomAccess::Allowlist => !allowlist.is_empty() && allowlist.len() <= MAX_ROOM_ALLOWLIST,
+            RoomAccess::JoinCode => *join_code_hash != [0u8; 32] && allowlist.is_empty(),
+        }
+    }
+
+    /// Check a bet against the room's maximum (0 = no maximum)
+    pub fn is_within_max_bet(&self, amount: u64) -> bool {
+        self.max_bet == 0 || amount <= self.max_bet
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::FeeTier;
+    use anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL;
+
+    fn sol_room(house_fee_basis_points: u16) -> Room {
+        let mut room = Room::deserialize(&mut &[0u8; Room::LEN][..]).unwrap();
+        room.house_fee_basis_points = house_fee_basis_points;
+        room
+    }
+
+    fn config_with_tiers(fee_tiers: Vec<FeeTier>) -> GameConfig {
+        let mut config = GameConfig::deserialize(&mut &[0u8; GameConfig::LEN][..]).unwrap();
+        config.fee_tiers = fee_tiers;
+        config
+    }
+
+    #[test]
+    fn stake_fee_is_the_stake_share_of_the_pot_fee() {
+        let room = sol_room(500);
+        let config = config_with_tiers(Vec::new());
+
+        assert_eq!(
+            room.calculate_stake_house_fee(&config, 2 * LAMPORTS_PER_SOL, 10 * LAMPORTS_PER_SOL),
+            2 * LAMPORTS_PER_SOL * 5 / 100
+        );
+        assert_eq!(room.calculate_stake_house_fee(&config, 0, 0), 0);
+    }
+
+    #[test]
+    fn stake_fee_uses_the_tier_of_the_whole_pot() {
+        let room = sol_room(500);
+        let config = config_with_tiers(vec![
+            FeeTier { pot_below: LAMPORTS_PER_SOL, fee_bps: 500 },
+            FeeTier { pot_below: u64::MAX, fee_bps: 300 },
+        ]);
+
+        assert_eq!(
+            room.calculate_stake_house_fee(&config, LAMPORTS_PER_SOL / 2, 20 * LAMPORTS_PER_SOL),
+            LAMPORTS_PER_SOL / 2 * 3 / 100
+        );
+    }
+
+    fn prize_split_room(prize_split_basis_points: [u16; MAX_PRIZE_PLACES]) -> Room {
+        let mut room = sol_room(500);
+        room.game_mode = GameMode::PrizeSplit;
+        room.prize_split_basis_points = prize_split_basis_points;
+        room
+    }
+
+    #[test]
+    fn prize_split_pays_each_place_its_share() {
+        let room = prize_split_room([6_000, 3_000, 1_000, 0, 0]);
+
+        assert_eq!(room.prize_places(), 3);
+        assert_eq!(room.calculate_prize_payouts(1_001, 3), vec![601, 300, 100]);
+    }
+
+    #[test]
+    fn unfilled_prize_places_go_to_the_winner() {
+        let room = prize_split_room([6_000, 3_000, 1_000, 0, 0]);
+        assert_eq!(room.calculate_prize_payouts(1_000, 2), vec![700, 300]);
+
+        let winner_takes_all = sol_room(500);
+        assert_eq!(winner_takes_all.calculate_prize_payouts(1_000, 4), vec![1_000]);
+    }
+
+    #[test]
+    fn prize_split_must_cover_the_whole_pot() {
+        assert!(Room::is_valid_prize_split(&[7_000, 3_000]));
+
+        assert!(!Room::is_valid_prize_split(&[]));
+        assert!(!Room::is_valid_prize_split(&[7_000, 2_000]));
+        assert!(!Room::is_valid_prize_split(&[10_000, 0]));
+        assert!(!Room::is_valid_prize_split(&[2_000; MAX_PRIZE_PLACES + 1]));
+    }
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::state::PlayerEntry;
+
+/// A paid place in the round's prize split
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PrizePlacement {
+    pub wallet: Pubkey,
+    pub payout: u64,
+}
+
+impl PrizePlacement {
+    /// Prize placement size: 32 (wallet) + 8 (payout) = 40 bytes
+    pub const LEN: usize = 32 + 8;
+}
+
+/// Permanent record of a resolved round, written by `unified_resolve_and_distribute`
+/// Seeds: [b"round_result", room_id.to_le_bytes(), round_id.to_le_bytes()]
+#[account]
+pub struct RoundResult {
+    pub room_id: u64,
+    pub round_id: u64,
+    pub winner: Pubkey,
+    pub players: Vec<PlayerEntry>,  // Snapshot of the round's players at resolution
+    pub bet_mint: Pubkey,           // Unit of all amounts below (Pubkey::default() = lamports)
+
+    // Payout breakdown
+    pub total_pot: u64,
+    pub winner_payout: u64,
+    pub house_fee: u64,
+    pub nft_bonus: u64,                  // Moved from the house fee to the winner for held champion NFTs
+    pub placements: Vec<PrizePlacement>, // Paid places, winner first
+    pub jackpot_contribution: u64,
+    pub jackpot_payout: u64,             // Non-zero when the winner hit the jackpot
+
+    // Replay data
+    pub randomness: [u8; 64],       // Full fulfilled ORAO VRF randomness
+    pub replay_seed: [u8; 32],      // Seed for crate::replay::elimination_order
+    pub resolved_at: i64,
+}
+
+impl RoundResult {
+    /// Account space calculation:
+    /// 8 (discriminator) + 8 (room_id) + 8 (round_id) + 32 (winner)
+    /// + 4 (players vec len) + (player_count * 66) + 32 (bet_mint)
+    /// + 8 (total_pot) + 8 (winner_payout) + 8 (house_fee) + 8 (nft_bonus)
+    /// + 4 (placements vec len) + (placement_count * 40)
+    /// + 8 (jackpot_contribution) + 8 (jackpot_payout)
+    /// + 64 (randomness) + 32 (replay_seed) + 8 (resolved_at)
+    pub fn space(player_count: usize, placement_count: usize) -> usize {
+        8 + 8 + 8 + 32 + 4 + (player_count * PlayerEntry::LEN) + 32 + 8 + 8 + 8 + 8
+            + 4 + (placement_count * PrizePlacement::LEN) + 8 + 8 + 64 + 32 + 8
+    }
+}
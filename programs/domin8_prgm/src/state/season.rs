@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+use crate::constants::{BASIS_POINTS_DENOMINATOR, MAX_PRIZE_PLACES, MAX_SEASON_LEADERBOARD};
+
+/// Weights of the season points formula
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct PointsFormula {
+    pub per_round_played: u64,
+    pub per_round_won: u64,
+    pub per_sol_wagered: u64,     // Per whole SOL wagered in native SOL rooms
+    pub per_sol_won: u64,         // Per whole SOL won in native SOL rooms
+    pub per_champion_nft: u64,    // Per champion NFT won during the season
+}
+
+impl PointsFormula {
+    pub const LEN: usize = 8 * 5; // 40 bytes
+}
+
+/// A leaderboard row
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SeasonEntry {
+    pub wallet: Pubkey,
+    pub points: u64,
+}
+
+impl SeasonEntry {
+    /// Entry size: 32 (wallet) + 8 (points) = 40 bytes
+    pub const LEN: usize = 32 + 8;
+}
+
+/// Seasonal leaderboard with a prize pool funded from the house fee.
+/// The pool is the account's lamports above rent; `prize_pool` mirrors it.
+/// Seeds: [b"season", season_id.to_le_bytes()]
+#[account]
+pub struct Season {
+    pub season_id: u64,
+    pub start_ts: i64,
+    pub end_ts: i64,
+    pub fee_share_bps: u16,                // Share of each round's house fee paid into the pool
+    pub points_formula: PointsFormula,
+    pub prize_split_basis_points: [u16; MAX_PRIZE_PLACES], // Zero-terminated, paid to the top wallets
+    pub prize_pool: u64,
+    pub finalized: bool,
+    pub leaderboard: Vec<SeasonEntry>,     // Top MAX_SEASON_LEADERBOARD wallets, highest first
+}
+
+impl Season {
+    /// Account space calculation:
+    /// 8 (discriminator) + 8 (season_id) + 8 (start_ts) + 8 (end_ts) + 2 (fee_share_bps)
+    /// + 40 (points_formula) + (5 * 2) (prize_split_basis_points) + 8 (prize_pool) + 1 (finalized)
+    /// + 4 + (20 * 40) (leaderboard) = 897 bytes
+    pub const LEN: usize = 8 + 8 + 8 + 8 + 2 + PointsFormula::LEN
+        + (MAX_PRIZE_PLACES * 2) + 8 + 1
+        + 4 + (MAX_SEASON_LEADERBOARD * SeasonEntry::LEN);
+
+    pub fn is_active(&self, now: i64) -> bool {
+        !self.finalized && now >= self.start_ts && now <= self.end_ts
+    }
+
+    /// Prize pool contribution taken from a round's house fee
+    pub fn calculate_contribution(&self, house_fee: u64) -> u64 {
+        ((house_fee as u128 * self.fee_share_bps as u128) / BASIS_POINTS_DENOMINATOR as u128) as u64
+    }
+
+    /// Insert or move a wallet on the leaderboard, keeping only the top entries
+    pub fn update_leaderboard(&mut self, wallet: Pubkey, points: u64) {
+        self.leaderboard.retain(|entry| entry.wallet != wallet);
+        // Ties keep the wallet that got there first
+        let position = self.leaderboard.partition_point(|entry| entry.points >= points);
+        self.leaderboard.insert(position, SeasonEntry { wallet, points });
+        self.leaderboard.truncate(MAX_SEASON_LEADERBOARD);
+    }
+
+    /// Number of paid places
+    pub fn prize_places(&self) -> usize {
+        self.prize_split_basis_points.iter().take_while(|bps| **bps > 0).count()
+    }
+
+    /// Split the prize pool across `placed_count` wallets.
+    /// Shares for places nobody filled, plus rounding dust, go to first place.
+    pub fn calculate_prize_payouts(&self, placed_count: usize) -> Vec<u64> {
+        let mut payouts = vec![0u64; placed_count.min(self.prize_places()).max(1)];
+        let mut paid = 0u64;
+        for (place, payout) in payouts.iter_mut().enumerate().skip(1) {
+            *payout = ((self.prize_pool as u128 * self.prize_split_basis_points[place] as u128)
+                / BASIS_POINTS_DENOMINATOR as u128) as u64;
+            paid = paid.saturating_add(*payout);
+        }
+        payouts[0] = self.prize_pool.saturating_sub(paid);
+        payouts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn season(prize_pool: u64, prize_split_basis_points: [u16; MAX_PRIZE_PLACES]) -> Season {
+        Season {
+            season_id: 1,
+            start_ts: 0,
+            end_ts: 100,
+            fee_share_bps: 0,
+            points_formula: PointsFormula::default(),
+            prize_split_basis_points,
+            prize_pool,
+            finalized: false,
+            leaderboard: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn leaderboard_stays_sorted_and_moves_existing_wallets() {
+        let mut season = season(0, [10_000, 0, 0, 0, 0]);
+        let (a, b, c) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+
+        season.update_leaderboard(a, 10);
+        season.update_leaderboard(b, 30);
+        season.update_leaderboard(c, 10);
+        season.update_leaderboard(a, 40);
+
+        let order: Vec<_> = season.leaderboard.iter().map(|entry| (entry.wallet, entry.points)).collect();
+        assert_eq!(order, vec![(a, 40), (b, 30), (c, 10)]);
+    }
+
+    #[test]
+    fn leaderboard_keeps_only_the_top_wallets() {
+        let mut season = season(0, [10_000, 0, 0, 0, 0]);
+        for points in 1..=(MAX_SEASON_LEADERBOARD as u64 + 1) {
+            season.update_leaderboard(Pubkey::new_unique(), points);
+        }
+
+        assert_eq!(season.leaderboard.len(), MAX_SEASON_LEADERBOARD);
+        assert_eq!(season.leaderboard.last().unwrap().points, 2);
+    }
+
+    #[test]
+    fn unfilled_places_and_dust_go_to_first_place() {
+        let season = season(1_001, [5_000, 3_000, 2_000, 0, 0]);
+
+        assert_eq!(season.calculate_prize_payouts(3), vec![501, 300, 200]);
+        assert_eq!(season.calculate_prize_payouts(2), vec![701, 300]);
+        assert_eq!(season.calculate_prize_payouts(10), vec![501, 300, 200]);
+    }
+}
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL;
+use crate::state::{PlayerStats, PointsFormula};
+
+/// A wallet's participation in one season. Season activity is the
+/// difference between the wallet's `PlayerStats` now and the baseline
+/// snapshotted when it joined.
+/// Seeds: [b"season_stats", season, wallet]
+#[account]
+pub struct SeasonStats {
+    pub season: Pubkey,
+    pub player: Pubkey,
+
+    // PlayerStats baseline at join time
+    pub baseline_rounds_played: u64,
+    pub baseline_rounds_won: u64,
+    pub baseline_wagered: u64,
+    pub baseline_won: u64,
+
+    pub champion_nfts: u64,   // Champion NFTs won during the season, each counted once
+    pub points: u64,
+}
+
+impl SeasonStats {
+    /// 8 (discriminator) + 32 (season) + 32 (player) + (4 * 8) (baseline)
+    /// + 8 (champion_nfts) + 8 (points) = 120 bytes
+    pub const LEN: usize = 8 + 32 + 32 + (4 * 8) + 8 + 8;
+
+    pub fn set_baseline(&mut self, player_stats: Option<&PlayerStats>) {
+        self.baseline_rounds_played = player_stats.map_or(0, |s| s.rounds_played);
+        self.baseline_rounds_won = player_stats.map_or(0, |s| s.rounds_won);
+        self.baseline_wagered = player_stats.map_or(0, |s| s.total_wagered);
+        self.baseline_won = player_stats.map_or(0, |s| s.total_won);
+    }
+
+    /// Season points from activity since joining
+    pub fn calculate_points(&self, player_stats: &PlayerStats, formula: &PointsFormula) -> u64 {
+        let played = player_stats.rounds_played.saturating_sub(self.baseline_rounds_played);
+        let won = player_stats.rounds_won.saturating_sub(self.baseline_rounds_won);
+        let wagered_sol = player_stats.total_wagered.saturating_sub(self.baseline_wagered) / LAMPORTS_PER_SOL;
+        let won_sol = player_stats.total_won.saturating_sub(self.baseline_won) / LAMPORTS_PER_SOL;
+
+        played.saturating_mul(formula.per_round_played)
+            .saturating_add(won.saturating_mul(formula.per_round_won))
+            .saturating_add(wagered_sol.saturating_mul(formula.per_sol_wagered))
+            .saturating_add(won_sol.saturating_mul(formula.per_sol_won))
+            .saturating_add(self.champion_nfts.saturating_mul(formula.per_champion_nft))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn player_stats(rounds_played: u64, rounds_won: u64, total_wagered: u64, total_won: u64) -> PlayerStats {
+        let mut stats = PlayerStats::deserialize(&mut &[0u8; PlayerStats::LEN][..]).unwrap();
+        stats.rounds_played = rounds_played;
+        stats.rounds_won = rounds_won;
+        stats.total_wagered = total_wagered;
+        stats.total_won = total_won;
+        stats
+    }
+
+    #[test]
+    fn points_count_only_activity_since_joining() {
+        let formula = PointsFormula {
+            per_round_played: 1,
+            per_round_won: 10,
+            per_sol_wagered: 100,
+            per_sol_won: 1_000,
+            per_champion_nft: 10_000,
+        };
+        let mut season_stats = SeasonStats::deserialize(&mut &[0u8; SeasonStats::LEN][..]).unwrap();
+        season_stats.set_baseline(Some(&player_stats(5, 2, 3 * LAMPORTS_PER_SOL, LAMPORTS_PER_SOL)));
+        season_stats.champion_nfts = 1;
+
+        // 3 rounds, 1 win, 2.5 SOL wagered and 1 SOL won since joining; partial SOL rounds down
+        let now = player_stats(8, 3, 11 * LAMPORTS_PER_SOL / 2, 2 * LAMPORTS_PER_SOL);
+        assert_eq!(season_stats.calculate_points(&now, &formula), 3 + 10 + 200 + 1_000 + 10_000);
+    }
+
+    #[test]
+    fn new_player_baseline_is_zero() {
+        let mut season_stats = SeasonStats::deserialize(&mut &[0u8; SeasonStats::LEN][..]).unwrap();
+        season_stats.set_baseline(None);
+
+        let formula = PointsFormula { per_round_played: 2, ..PointsFormula::default() };
+        assert_eq!(season_stats.calculate_points(&player_stats(4, 0, 0, 0), &formula), 8);
+    }
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+/// Authorizes an ephemeral key to bet from its owner's Game Coins balance
+/// up to a spending limit until it expires. Created and revoked by the owner.
+/// Seeds: [b"session_token", owner, session_key]
+#[account]
+pub struct SessionToken {
+    pub owner: Pubkey,
+    pub session_key: Pubkey,
+    pub spending_limit: u64,     // Lamports the session key may bet in total
+    pub spent: u64,
+    pub expires_at: i64,
+    pub created_at: i64,
+}
+
+impl SessionToken {
+    /// 8 (discriminator) + 32 (owner) + 32 (session_key) + 8 (spending_limit)
+    /// + 8 (spent) + 8 (expires_at) + 8 (created_at) = 104 bytes
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 8 + 8;
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        now >= self.expires_at
+    }
+
+    /// Lamports the session key can still bet
+    pub fn remaining_limit(&self) -> u64 {
+        self.spending_limit.saturating_sub(self.spent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(spending_limit: u64, spent: u64, expires_at: i64) -> SessionToken {
+        SessionToken {
+            owner: Pubkey::new_unique(),
+            session_key: Pubkey::new_unique(),
+            spending_limit,
+            spent,
+            expires_at,
+            created_at: 0,
+        }
+    }
+
+    #[test]
+    fn session_expires_at_its_deadline() {
+        let session = session(100, 0, 1_000);
+
+        assert!(!session.is_expired(999));
+        assert!(session.is_expired(1_000));
+    }
+
+    #[test]
+    fn remaining_limit_never_goes_below_zero() {
+        assert_eq!(session(100, 40, 1_000).remaining_limit(), 60);
+        assert_eq!(session(100, 100, 1_000).remaining_limit(), 0);
+        assert_eq!(session(100, 150, 1_000).remaining_limit(), 0);
+    }
+}
@@ -0,0 +1,156 @@
+use anchor_lang::prelude::*;
+use crate::constants::{
+    BASIS_POINTS_DENOMINATOR, MAX_PRIZE_PLACES, MAX_TOURNAMENT_ENTRANTS, TOURNAMENT_STALL_TIMEOUT,
+};
+
+/// Tournament lifecycle
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub enum TournamentStatus {
+    Registration,   // Accepting buy-ins, no round played yet
+    Active,         // Rounds in progress, registration closed
+    Finished,       // All rounds played, prize pool awaiting distribution
+    Paid,           // Prize pool distributed
+    Cancelled,      // Cancelled during registration or after stalling, buy-ins refunded
+}
+
+impl TournamentStatus {
+    pub const LEN: usize = 1; // Enum is 1 byte
+}
+
+/// An entrant's running score
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TournamentStanding {
+    pub wallet: Pubkey,
+    pub points: u64,
+    pub rounds_won: u8,
+}
+
+impl TournamentStanding {
+    /// Standing size: 32 (wallet) + 8 (points) + 1 (rounds_won) = 41 bytes
+    pub const LEN: usize = 32 + 8 + 1;
+}
+
+/// Multi-round tournament played in one room. Buy-ins are held as the
+/// account's lamports and paid to the top standings after the final round.
+/// Seeds: [b"tournament", tournament_id.to_le_bytes()]
+#[account]
+pub struct Tournament {
+    pub tournament_id: u64,
+    pub room_id: u64,                    // Room whose rounds count towards this tournament
+    pub buy_in: u64,                     // Lamports per entrant, added to the prize pool
+    pub total_rounds: u8,
+    pub rounds_played: u8,
+    pub status: TournamentStatus,
+    pub prize_pool: u64,
+    pub prize_split_basis_points: [u16; MAX_PRIZE_PLACES], // Zero-terminated, paid to the top standings
+    pub standings: Vec<TournamentStanding>,                // In registration order
+    pub last_activity_at: i64,           // Creation, first bet or last scored round
+}
+
+impl Tournament {
+    /// Account space calculation:
+    /// 8 (discriminator) + 8 (tournament_id) + 8 (room_id) + 8 (buy_in)
+    /// + 1 (total_rounds) + 1 (rounds_played) + 1 (status) + 8 (prize_pool)
+    /// + (5 * 2) (prize_split_basis_points) + 4 + (64 * 41) (standings)
+    /// + 8 (last_activity_at) = 2689 bytes
+    pub const LEN: usize = 8 + 8 + 8 + 8 + 1 + 1 + TournamentStatus::LEN + 8
+        + (MAX_PRIZE_PLACES * 2)
+        + 4 + (MAX_TOURNAMENT_ENTRANTS * TournamentStanding::LEN)
+        + 8;
+
+    pub fn find_standing_mut(&mut self, wallet: &Pubkey) -> Option<&mut TournamentStanding> {
+        self.standings.iter_mut().find(|s| s.wallet == *wallet)
+    }
+
+    pub fn is_entrant(&self, wallet: &Pubkey) -> bool {
+        self.standings.iter().any(|s| s.wallet == *wallet)
+    }
+
+    /// Registration can always be cancelled; an active tournament only once no
+    /// round has been scored for `TOURNAMENT_STALL_TIMEOUT`
+    pub fn can_cancel(&self, now: i64) -> bool {
+        match self.status {
+            TournamentStatus::Registration => true,
+            TournamentStatus::Active => now >= self.last_activity_at.saturating_add(TOURNAMENT_STALL_TIMEOUT),
+            _ => false,
+        }
+    }
+
+    /// Points for winning a round: the share of the pot put up by the other
+    /// players, in basis points. Beating a big field with a small stake scores most.
+    pub fn calculate_points(winner_bet: u64, total_pot: u64) -> u64 {
+        if total_pot == 0 {
+            return 0;
+        }
+        ((total_pot.saturating_sub(winner_bet) as u128 * BASIS_POINTS_DENOMINATOR as u128) / total_pot as u128) as u64
+    }
+
+    /// Entrants ordered by points, ties going to the earlier registration
+    pub fn ranking(&self) -> Vec<Pubkey> {
+        let mut ranked: Vec<&TournamentStanding> = self.standings.iter().collect();
+        ranked.sort_by_key(|s| std::cmp::Reverse(s.points));
+        ranked.into_iter().map(|s| s.wallet).collect()
+    }
+
+    /// Number of paid places
+    pub fn prize_places(&self) -> usize {
+        self.prize_split_basis_points.iter().take_while(|bps| **bps > 0).count()
+    }
+
+    /// Split the prize pool across `placed_count` standings.
+    /// Shares for places nobody filled, plus rounding dust, go to first place.
+    pub fn calculate_prize_payouts(&self, placed_count: usize) -> Vec<u64> {
+        let mut payouts = vec![0u64; placed_count.min(self.prize_places()).max(1)];
+        let mut paid = 0u64;
+        for (place, payout) in payouts.iter_mut().enumerate().skip(1) {
+            *payout = ((self.prize_pool as u128 * self.prize_split_basis_points[place] as u128)
+                / BASIS_POINTS_DENOMINATOR as u128) as u64;
+            paid = paid.saturating_add(*payout);
+        }
+        payouts[0] = self.prize_pool.saturating_sub(paid);
+        payouts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tournament_with_status(status: TournamentStatus, last_activity_at: i64) -> Tournament {
+        Tournament {
+            tournament_id: 1,
+            room_id: 0,
+            buy_in: 100_000_000,
+            total_rounds: 3,
+            rounds_played: 0,
+            status,
+            prize_pool: 0,
+            prize_split_basis_points: [10_000, 0, 0, 0, 0],
+            standings: Vec::new(),
+            last_activity_at,
+        }
+    }
+
+    #[test]
+    fn registration_can_always_be_cancelled() {
+        let tournament = tournament_with_status(TournamentStatus::Registration, 1_000);
+
+        assert!(tournament.can_cancel(1_000));
+    }
+
+    #[test]
+    fn active_tournament_can_be_cancelled_only_after_stalling() {
+        let tournament = tournament_with_status(TournamentStatus::Active, 1_000);
+
+        assert!(!tournament.can_cancel(1_000 + TOURNAMENT_STALL_TIMEOUT - 1));
+        assert!(tournament.can_cancel(1_000 + TOURNAMENT_STALL_TIMEOUT));
+    }
+
+    #[test]
+    fn finished_paid_and_cancelled_tournaments_cannot_be_cancelled() {
+        for status in [TournamentStatus::Finished, TournamentStatus::Paid, TournamentStatus::Cancelled] {
+            let tournament = tournament_with_status(status, 0);
+            assert!(!tournament.can_cancel(i64::MAX));
+        }
+    }
+}